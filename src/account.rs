@@ -81,10 +81,161 @@ pub struct UserPresence {
     pub game_name: Option<String>,
 }
 
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+pub struct GroupMembership {
+    pub group_id: u64,
+    pub name: String,
+    pub role: String,
+    pub rank: u32,
+}
+
+/// Profile data shown in the account detail view, cached until the next fetch
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+pub struct AccountDetails {
+    /// ISO 8601 creation timestamp as returned by the users API
+    pub created: Option<String>,
+    pub follower_count: u64,
+    pub following_count: u64,
+    pub has_verified_badge: bool,
+    #[serde(default)]
+    pub groups: Vec<GroupMembership>,
+    pub full_body_url: Option<String>,
+    /// `None` when the inventory is private
+    pub collectibles_count: Option<u32>,
+}
+
+/// A limited item held by the account, as listed by the collectibles inventory endpoint
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+pub struct CollectibleItem {
+    pub user_asset_id: u64,
+    pub asset_id: u64,
+    pub name: String,
+    /// Only limited-unique items carry a serial
+    pub serial: Option<u64>,
+    /// Recent average price in Robux
+    pub rap: u64,
+}
+
+/// Total RAP at a point in time, recorded on each inventory fetch
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+pub struct ValueSnapshot {
+    /// Local time as `%Y-%m-%d %H:%M`
+    pub time: String,
+    pub total_rap: u64,
+    pub item_count: u32,
+}
+
+/// A saved batch launch, started in one click or with `--preset <name>`
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+pub struct LaunchPreset {
+    pub name: String,
+    /// Accounts by user ID so renames and reordering don't break the preset
+    pub user_ids: Vec<u64>,
+    /// Empty to just open the client
+    pub place_id: String,
+    #[serde(default)]
+    pub job_id: Option<String>,
+    /// Share link or access code; takes priority over `job_id`
+    #[serde(default)]
+    pub private_server_link: Option<String>,
+    /// Seconds between launches
+    pub delay_secs: u32,
+    /// Turn multi-instance on before launching
+    pub multi_instance: bool,
+}
+
+/// Per-account launch settings used by the quick-launch button. Empty fields fall back
+/// to the global game selection and locale.
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+pub struct LaunchDefaults {
+    #[serde(default)]
+    pub place_id: String,
+    /// Share link or access code, used instead of `place_id`
+    #[serde(default)]
+    pub private_server_link: String,
+    #[serde(default)]
+    pub locale: String,
+    /// Go back to `RobloxAccount::last_server` when it's known
+    #[serde(default)]
+    pub rejoin_last_server: bool,
+}
+
+impl LaunchDefaults {
+    /// True if any setting overrides the global selection
+    pub fn is_set(&self) -> bool {
+        *self != Self::default()
+    }
+    
+    /// Short description of what's overridden, for tooltips
+    pub fn summary(&self) -> String {
+        let mut parts = Vec::new();
+        if self.rejoin_last_server {
+            parts.push("rejoins last server".to_string());
+        }
+        if !self.private_server_link.trim().is_empty() {
+            parts.push("private server".to_string());
+        } else if !self.place_id.trim().is_empty() {
+            parts.push(format!("place {}", self.place_id.trim()));
+        }
+        if !self.locale.trim().is_empty() {
+            parts.push(format!("locale {}", self.locale.trim()));
+        }
+        parts.join(", ")
+    }
+}
+
+/// Server an account was last seen playing in
+#[derive(Serialize, Deserialize, Clone, Default, Debug, PartialEq)]
+pub struct LastServer {
+    pub place_id: u64,
+    pub job_id: String,
+}
+
+/// Robux balance at a point in time, recorded on each info fetch
+#[derive(Serialize, Deserialize, Clone, Default, Debug)]
+pub struct RobuxPoint {
+    /// Local time as `%Y-%m-%d %H:%M`
+    pub time: String,
+    pub robux: i64,
+}
+
+/// Oldest history points are dropped past this many
+const MAX_HISTORY_POINTS: usize = 500;
+
+/// Append a snapshot, replacing the last one if it was taken in the same minute
+pub fn push_value_snapshot(history: &mut Vec<ValueSnapshot>, snapshot: ValueSnapshot) {
+    if history.last().is_some_and(|last| last.time == snapshot.time) {
+        history.pop();
+    }
+    history.push(snapshot);
+    if history.len() > MAX_HISTORY_POINTS {
+        let excess = history.len() - MAX_HISTORY_POINTS;
+        history.drain(..excess);
+    }
+}
+
+impl AccountDetails {
+    pub fn created_date(&self) -> Option<chrono::NaiveDate> {
+        let created = self.created.as_deref()?;
+        chrono::DateTime::parse_from_rfc3339(created)
+            .ok()
+            .map(|dt| dt.date_naive())
+    }
+    
+    pub fn age_days(&self) -> Option<i64> {
+        let created = self.created_date()?;
+        Some((chrono::Local::now().date_naive() - created).num_days())
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Default)]
 pub struct RobloxAccount {
     pub username: String,
     pub password: String,
+    /// New password written before a change request is sent and cleared once the outcome
+    /// is known. If still set, the change may have gone through and this is the live password.
+    #[serde(default)]
+    pub pending_password: Option<String>,
     pub cookie: Option<String>,
     pub user_id: Option<u64>,
     pub display_name: Option<String>,
@@ -106,6 +257,22 @@ pub struct RobloxAccount {
     pub last_info_fetch: Option<String>,
     #[serde(default)]
     pub avatar_url: Option<String>,
+    #[serde(default)]
+    pub details: Option<AccountDetails>,
+    /// Limited items from the last inventory fetch
+    #[serde(default)]
+    pub collectibles: Vec<CollectibleItem>,
+    #[serde(default)]
+    pub value_history: Vec<ValueSnapshot>,
+    #[serde(default)]
+    pub robux_history: Vec<RobuxPoint>,
+    /// Pending incoming friend requests at the last check
+    #[serde(default)]
+    pub pending_friend_requests: Option<u32>,
+    #[serde(default)]
+    pub launch_defaults: LaunchDefaults,
+    #[serde(default)]
+    pub last_server: Option<LastServer>,
     #[serde(skip)]
     pub presence: Option<UserPresence>,
 }
@@ -125,7 +292,14 @@ pub struct UserGame {
     pub universe_id: Option<u64>,
 }
 
-#[derive(Serialize, Deserialize, Default)]
+/// Drop alert for new installs and configs saved before the setting existed
+const DEFAULT_ROBUX_DROP_ALERT: u32 = 1000;
+
+fn default_robux_drop_alert() -> u32 {
+    DEFAULT_ROBUX_DROP_ALERT
+}
+
+#[derive(Serialize, Deserialize)]
 pub struct AppData {
     pub accounts: Vec<RobloxAccount>,
     pub last_place_id: String,
@@ -143,9 +317,146 @@ pub struct AppData {
     pub batch_launch_delay: u32,
     #[serde(default)]
     pub minimize_to_tray: bool,
+    /// Chrome/Chromium executable for browser sessions, empty to auto-detect
+    #[serde(default)]
+    pub browser_path: String,
+    /// Combined RAP across all accounts, recorded after each fetch of every account
+    #[serde(default)]
+    pub value_history: Vec<ValueSnapshot>,
+    /// Warn when an account loses more than this many Robux between two checks, 0 to disable
+    #[serde(default = "default_robux_drop_alert")]
+    pub robux_drop_alert: u32,
+    /// How launches reach the client on Linux and macOS
+    #[serde(default)]
+    pub launcher_backend: crate::api::LauncherBackend,
+    /// Client locale sent with every launch, empty for en_us
+    #[serde(default)]
+    pub launch_locale: String,
+    /// Client release channel sent with every launch, empty for production
+    #[serde(default)]
+    pub launch_channel: String,
+    #[serde(default)]
+    pub launch_presets: Vec<LaunchPreset>,
+    /// Where `save` writes, None to keep everything in memory
+    #[serde(skip)]
+    pub save_path: Option<PathBuf>,
+}
+
+impl RobloxAccount {
+    /// Store fresh presence, remembering the server if the account is in one
+    pub fn set_presence(&mut self, presence: UserPresence) {
+        if presence.presence_type == UserPresenceType::InGame {
+            if let (Some(place_id), Some(job_id)) = (presence.place_id, presence.game_id.clone()) {
+                self.last_server = Some(LastServer { place_id, job_id });
+            }
+        }
+        self.presence = Some(presence);
+    }
+    
+    /// True if `last_info_fetch` is more recent than `max_age_minutes`
+    pub fn info_is_fresh(&self, max_age_minutes: i64) -> bool {
+        self.last_info_fetch.as_deref()
+            .and_then(|t| chrono::NaiveDateTime::parse_from_str(t, "%Y-%m-%d %H:%M").ok())
+            .map(|t| (chrono::Local::now().naive_local() - t).num_minutes() < max_age_minutes)
+            .unwrap_or(false)
+    }
+    
+    /// Store a fetched balance and add it to the history.
+    /// A second fetch within the same minute replaces the previous point.
+    pub fn record_robux(&mut self, robux: i64, time: String) {
+        self.robux = Some(robux);
+        if self.robux_history.last().is_some_and(|last| last.time == time) {
+            self.robux_history.pop();
+        }
+        self.robux_history.push(RobuxPoint { time, robux });
+        if self.robux_history.len() > MAX_HISTORY_POINTS {
+            let excess = self.robux_history.len() - MAX_HISTORY_POINTS;
+            self.robux_history.drain(..excess);
+        }
+    }
+    
+    /// Indices in `robux_history` where the balance fell by more than `threshold` since the previous point
+    pub fn robux_drops(&self, threshold: u32) -> Vec<usize> {
+        if threshold == 0 {
+            return Vec::new();
+        }
+        self.robux_history.windows(2)
+            .enumerate()
+            .filter(|(_, pair)| pair[0].robux - pair[1].robux > threshold as i64)
+            .map(|(i, _)| i + 1)
+            .collect()
+    }
+    
+    /// Robux lost at the latest check, if it exceeds `threshold`
+    pub fn latest_robux_drop(&self, threshold: u32) -> Option<i64> {
+        let last = self.robux_history.len().checked_sub(1)?;
+        self.robux_drops(threshold).last()
+            .filter(|&&i| i == last)
+            .map(|_| self.robux_history[last - 1].robux - self.robux_history[last].robux)
+    }
+    
+    /// Sum of recent average prices over the fetched collectibles
+    pub fn inventory_value(&self) -> u64 {
+        self.collectibles.iter().map(|item| item.rap).sum()
+    }
+}
+
+impl Default for AppData {
+    fn default() -> Self {
+        Self {
+            accounts: Vec::new(),
+            last_place_id: String::new(),
+            favorite_games: Vec::new(),
+            multi_instance_enabled: false,
+            recent_games: Vec::new(),
+            user_games: Vec::new(),
+            auto_refresh_cookies: false,
+            batch_launch_delay: 0,
+            minimize_to_tray: false,
+            browser_path: String::new(),
+            value_history: Vec::new(),
+            robux_drop_alert: DEFAULT_ROBUX_DROP_ALERT,
+            launcher_backend: Default::default(),
+            launch_locale: String::new(),
+            launch_channel: String::new(),
+            launch_presets: Vec::new(),
+            save_path: None,
+        }
+    }
 }
 
 impl AppData {
+    /// Index of the preset called `name`, ignoring case
+    pub fn find_preset(&self, name: &str) -> Option<usize> {
+        let name = name.trim();
+        self.launch_presets.iter().position(|p| p.name.eq_ignore_ascii_case(name))
+    }
+    
+    /// Store `preset` in place of the one at `replacing`, overwriting any other preset with the same name
+    pub fn save_preset(&mut self, preset: LaunchPreset, replacing: Option<usize>) {
+        let replacing = replacing.filter(|i| *i < self.launch_presets.len());
+        if let Some(i) = replacing {
+            self.launch_presets.remove(i);
+        }
+        match (self.find_preset(&preset.name), replacing) {
+            (Some(i), _) => self.launch_presets[i] = preset,
+            (None, Some(i)) => self.launch_presets.insert(i, preset),
+            (None, None) => self.launch_presets.push(preset),
+        }
+    }
+    
+    /// Combined balance of every account with a known Robux count
+    pub fn total_robux(&self) -> i64 {
+        self.accounts.iter().filter_map(|a| a.robux).sum()
+    }
+    
+    /// Combined RAP and item count across all accounts
+    pub fn total_inventory_value(&self) -> (u64, u32) {
+        self.accounts.iter().fold((0, 0), |(value, count), account| {
+            (value + account.inventory_value(), count + account.collectibles.len() as u32)
+        })
+    }
+    
     pub fn config_path() -> PathBuf {
         let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
         path.push("NexusAccountManager");
@@ -155,21 +466,81 @@ impl AppData {
     }
 
     pub fn load() -> Self {
-        let path = Self::config_path();
-        if path.exists() {
+        Self::load_from(Self::config_path())
+    }
+    
+    /// Read the data saved at `path`, saving back there from then on
+    pub fn load_from(path: PathBuf) -> Self {
+        let mut data: Self = if path.exists() {
             fs::read_to_string(&path)
                 .ok()
                 .and_then(|data| serde_json::from_str(&data).ok())
                 .unwrap_or_default()
         } else {
             Self::default()
-        }
+        };
+        data.save_path = Some(path);
+        data
     }
 
     pub fn save(&self) {
-        let path = Self::config_path();
+        let Some(path) = &self.save_path else { return };
         if let Ok(data) = serde_json::to_string_pretty(self) {
             fs::write(path, data).ok();
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn account_with_history(balances: &[i64]) -> RobloxAccount {
+        let mut account = RobloxAccount::default();
+        for (i, robux) in balances.iter().enumerate() {
+            account.record_robux(*robux, format!("2024-01-01 00:{:02}", i));
+        }
+        account
+    }
+    
+    #[test]
+    fn test_robux_drops() {
+        let account = account_with_history(&[1000, 400, 450, 300, 2000, 0]);
+        assert_eq!(account.robux_drops(100), vec![1, 3, 5]);
+        assert_eq!(account.robux_drops(500), vec![1, 5]);
+        assert!(account.robux_drops(0).is_empty());
+        assert_eq!(account.latest_robux_drop(500), Some(2000));
+        
+        let recovered = account_with_history(&[1000, 100, 150]);
+        assert_eq!(recovered.latest_robux_drop(500), None);
+        
+        // Same-minute fetches replace rather than append
+        let mut account = account_with_history(&[1000]);
+        account.record_robux(900, "2024-01-01 00:00".to_string());
+        assert_eq!(account.robux_history.len(), 1);
+        assert_eq!(account.robux, Some(900));
+        
+        // Alerts are on out of the box, including for configs from before the setting
+        assert_eq!(AppData::default().robux_drop_alert, DEFAULT_ROBUX_DROP_ALERT);
+        let old: AppData = serde_json::from_str(r#"{"accounts": [], "last_place_id": ""}"#).unwrap();
+        assert_eq!(old.robux_drop_alert, DEFAULT_ROBUX_DROP_ALERT);
+    }
+    
+    #[test]
+    fn test_save_goes_to_load_path() {
+        let path = std::env::temp_dir().join(format!("nexus-accounts-{}.json", std::process::id()));
+        fs::remove_file(&path).ok();
+        
+        // Data that wasn't loaded from disk never touches it
+        let data = AppData { last_place_id: "1818".to_string(), ..Default::default() };
+        data.save();
+        
+        let mut data = AppData::load_from(path.clone());
+        assert!(data.last_place_id.is_empty());
+        data.last_place_id = "920587237".to_string();
+        data.save();
+        
+        assert_eq!(AppData::load_from(path.clone()).last_place_id, "920587237");
+        fs::remove_file(&path).ok();
+    }
+}
@@ -1,19 +1,48 @@
-use crate::account::{UserPresence, UserPresenceType};
+use crate::account::{AccountDetails, CollectibleItem, GroupMembership, UserPresence, UserPresenceType};
+use super::launch_request::{LaunchMode, LaunchRequest};
+use super::launcher::LaunchOptions;
+use super::privacy::{PrivacySetting, PRIVACY_SETTINGS};
 use std::collections::HashMap;
-use std::process::Command;
-use std::time::Duration;
-
-#[cfg(windows)]
-use std::os::windows::process::CommandExt;
-
-#[cfg(windows)]
-const CREATE_NO_WINDOW: u32 = 0x08000000;
 
 #[derive(Default)]
 pub struct AccountInfo {
-    pub robux: i64,
+    /// `None` when the balance couldn't be fetched
+    pub robux: Option<i64>,
     pub friends_count: u32,
     pub is_premium: bool,
+    pub friend_requests: u32,
+}
+
+/// A user on an account's block list
+#[derive(Clone, Default)]
+pub struct BlockedUser {
+    pub user_id: u64,
+    pub username: String,
+    pub display_name: String,
+    pub avatar_url: Option<String>,
+}
+
+/// A pending incoming friend request
+#[derive(Clone, Default)]
+pub struct FriendRequest {
+    pub user_id: u64,
+    pub username: String,
+    pub display_name: String,
+    /// Sender's account creation time, ISO 8601
+    pub created: Option<String>,
+    /// When the request was sent, ISO 8601
+    pub sent_at: Option<String>,
+    pub mutual_friends: Vec<String>,
+}
+
+/// One entry of an account's friends list, with headshot and live presence
+#[derive(Clone, Default)]
+pub struct FriendInfo {
+    pub user_id: u64,
+    pub username: String,
+    pub display_name: String,
+    pub headshot_url: Option<String>,
+    pub presence: Option<UserPresence>,
 }
 
 fn run_async<F, T>(future: F) -> Result<T, String>
@@ -30,18 +59,19 @@ where
 }
 
 fn format_cookie_str(cookie: &str) -> String {
-    let cookie = cookie.trim();
-    if cookie.starts_with("_|WARNING:-DO-NOT-SHARE-THIS.--Sharing-this-will-allow-someone-to-log-in-as-you-and-to-steal-your-ROBUX-and-items.|_") {
-        cookie.to_string()
-    } else {
-        cookie.to_string()
-    }
+    crate::auth::parse_cookie(cookie).unwrap_or_else(|| cookie.trim().to_string())
 }
 
 pub struct RobloxApi;
 
 impl RobloxApi {
     pub fn validate_cookie(cookie: &str) -> Result<(u64, String), String> {
+        Self::check_cookie(cookie)?.ok_or_else(|| "Cookie is invalid or expired".to_string())
+    }
+    
+    /// User ID and display name for a cookie, `None` if Roblox rejects it.
+    /// Errors only when Roblox couldn't be asked.
+    pub fn check_cookie(cookie: &str) -> Result<Option<(u64, String)>, String> {
         let cookie = cookie.to_string();
         
         run_async(async move {
@@ -55,8 +85,11 @@ impl RobloxApi {
                 .await
                 .map_err(|e| format!("Request failed: {}", e))?;
 
+            if resp.status() == reqwest::StatusCode::UNAUTHORIZED {
+                return Ok(None);
+            }
             if !resp.status().is_success() {
-                return Err("Cookie is invalid or expired".to_string());
+                return Err(format!("Roblox returned {}", resp.status()));
             }
 
             let info: serde_json::Value = resp.json().await.map_err(|_| "Failed to parse response")?;
@@ -64,7 +97,7 @@ impl RobloxApi {
             let user_id = info.get("id").and_then(|v| v.as_u64()).ok_or("No user ID in response")?;
             let display_name = info.get("displayName").and_then(|v| v.as_str()).unwrap_or("Unknown").to_string();
 
-            Ok((user_id, display_name))
+            Ok(Some((user_id, display_name)))
         })
     }
 
@@ -84,8 +117,10 @@ impl RobloxApi {
                 .send()
                 .await
             {
-                if let Ok(data) = resp.json::<serde_json::Value>().await {
-                    info.robux = data.get("robux").and_then(|v| v.as_i64()).unwrap_or(0);
+                if resp.status().is_success() {
+                    if let Ok(data) = resp.json::<serde_json::Value>().await {
+                        info.robux = data.get("robux").and_then(|v| v.as_i64());
+                    }
                 }
             }
             
@@ -109,16 +144,34 @@ impl RobloxApi {
                 info.is_premium = resp.status().is_success();
             }
             
+            if let Ok(resp) = client
+                .get("https://friends.roblox.com/v1/user/friend-requests/count")
+                .header("Cookie", &cookie_header)
+                .send()
+                .await
+            {
+                if let Ok(data) = resp.json::<serde_json::Value>().await {
+                    info.friend_requests = data.get("count").and_then(|v| v.as_u64()).unwrap_or(0) as u32;
+                }
+            }
+            
             Ok(info)
         })
     }
 
     pub fn get_presence(user_ids: &[u64]) -> Result<HashMap<u64, UserPresence>, String> {
+        Self::get_presence_as(None, user_ids)
+    }
+    
+    /// Presence seen from an account. Signed in, Roblox also reports the place and
+    /// server of friends who share their joins, which anonymous requests don't get.
+    pub fn get_presence_as(cookie: Option<&str>, user_ids: &[u64]) -> Result<HashMap<u64, UserPresence>, String> {
         if user_ids.is_empty() {
             return Ok(HashMap::new());
         }
         
         let user_ids = user_ids.to_vec();
+        let cookie_header = cookie.map(|c| format!(".ROBLOSECURITY={}", format_cookie_str(c)));
         
         run_async(async move {
             let client = reqwest::Client::new();
@@ -127,9 +180,14 @@ impl RobloxApi {
                 "userIds": user_ids
             });
             
-            let resp = client
+            let mut request = client
                 .post("https://presence.roblox.com/v1/presence/users")
-                .json(&body)
+                .json(&body);
+            if let Some(ref cookie_header) = cookie_header {
+                request = request.header("Cookie", cookie_header);
+            }
+            
+            let resp = request
                 .send()
                 .await
                 .map_err(|e| format!("Request failed: {}", e))?;
@@ -166,6 +224,80 @@ impl RobloxApi {
         })
     }
 
+    /// Friends of `user_id` with headshots and presence as seen by the signed-in account
+    pub fn get_friends(cookie: &str, user_id: u64) -> Result<Vec<FriendInfo>, String> {
+        let cookie_owned = cookie.to_string();
+        
+        let mut friends = run_async(async move {
+            let client = reqwest::Client::new();
+            let cookie_header = format!(".ROBLOSECURITY={}", format_cookie_str(&cookie_owned));
+            
+            let resp = client
+                .get(format!("https://friends.roblox.com/v1/users/{}/friends", user_id))
+                .header("Cookie", &cookie_header)
+                .send()
+                .await
+                .map_err(|e| format!("Request failed: {}", e))?;
+            
+            if !resp.status().is_success() {
+                return Err(format!("Failed: HTTP {}", resp.status()));
+            }
+            
+            let data: serde_json::Value = resp.json().await
+                .map_err(|e| format!("Failed to parse: {}", e))?;
+            
+            let mut friends: Vec<FriendInfo> = data.get("data")
+                .and_then(|d| d.as_array())
+                .map(|arr| arr.iter().map(|f| FriendInfo {
+                    user_id: f.get("id").and_then(|v| v.as_u64()).unwrap_or(0),
+                    username: f.get("name").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+                    display_name: f.get("displayName").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+                    ..Default::default()
+                }).collect())
+                .unwrap_or_default();
+            
+            // The friends endpoint may leave names blank; resolve those through the users API
+            let unnamed: Vec<u64> = friends.iter()
+                .filter(|f| f.username.is_empty())
+                .map(|f| f.user_id)
+                .collect();
+            
+            for chunk in unnamed.chunks(100) {
+                let body = serde_json::json!({ "userIds": chunk, "excludeBannedUsers": false });
+                let Ok(resp) = client.post("https://users.roblox.com/v1/users").json(&body).send().await else {
+                    continue;
+                };
+                let Ok(data) = resp.json::<serde_json::Value>().await else {
+                    continue;
+                };
+                
+                for user in data.get("data").and_then(|d| d.as_array()).into_iter().flatten() {
+                    let id = user.get("id").and_then(|v| v.as_u64()).unwrap_or(0);
+                    if let Some(friend) = friends.iter_mut().find(|f| f.user_id == id) {
+                        friend.username = user.get("name").and_then(|v| v.as_str()).unwrap_or("").to_string();
+                        friend.display_name = user.get("displayName").and_then(|v| v.as_str()).unwrap_or("").to_string();
+                    }
+                }
+            }
+            
+            Ok(friends)
+        })?;
+        
+        // Both endpoints take at most 100 IDs per request
+        let ids: Vec<u64> = friends.iter().map(|f| f.user_id).collect();
+        for chunk in ids.chunks(100) {
+            let headshots = Self::get_avatar_thumbnails(chunk).unwrap_or_default();
+            let presences = Self::get_presence_as(Some(cookie), chunk).unwrap_or_default();
+            
+            for friend in friends.iter_mut().filter(|f| chunk.contains(&f.user_id)) {
+                friend.headshot_url = headshots.get(&friend.user_id).cloned();
+                friend.presence = presences.get(&friend.user_id).cloned();
+            }
+        }
+        
+        Ok(friends)
+    }
+
     pub fn get_game_info(place_id: &str) -> Result<(String, String), String> {
         let place_id = place_id.to_string();
         
@@ -274,137 +406,77 @@ impl RobloxApi {
         })
     }
 
-    #[cfg(windows)]
-    pub fn set_account_and_launch(cookie: &str, place_id: Option<&str>) -> Result<(), String> {
-        Self::launch_with_options(cookie, place_id, false)
-    }
-    
-    #[cfg(windows)]
-    pub fn launch_multi_instance(cookie: &str, place_id: Option<&str>) -> Result<(), String> {
-        Self::launch_with_options(cookie, place_id, true)
-    }
-
-    #[cfg(windows)]
-    fn launch_with_options(cookie: &str, place_id: Option<&str>, multi_instance: bool) -> Result<(), String> {
-        Self::launch_with_job_id(cookie, place_id, None, multi_instance)
+    /// Close running clients unless launching alongside them, in which case
+    /// only the account's own earlier client is replaced
+    fn close_clients(options: &LaunchOptions) {
+        if options.multi_instance {
+            options.clients.close(&options.account, options.platform.as_ref()).ok();
+        } else {
+            options.platform.kill_all_clients(options);
+        }
     }
     
     /// Launch with a specific Job ID (server)
-    #[cfg(windows)]
-    pub fn launch_with_job_id(cookie: &str, place_id: Option<&str>, job_id: Option<&str>, multi_instance: bool) -> Result<(), String> {
-        if !multi_instance {
-            Command::new("taskkill").args(["/F", "/IM", "RobloxPlayerBeta.exe"]).creation_flags(CREATE_NO_WINDOW).output().ok();
-            Command::new("taskkill").args(["/F", "/IM", "Roblox.exe"]).creation_flags(CREATE_NO_WINDOW).output().ok();
-            std::thread::sleep(Duration::from_millis(500));
-        }
-
-        let auth_ticket = Self::get_auth_ticket(cookie)?;
-
-        let browser_tracker_id: u64 = rand::random::<u64>() % 1_000_000_000_000;
-        
-        let place_id_str = place_id.unwrap_or("1818").trim();
-        let place_id_num = if place_id_str.is_empty() { "1818" } else { place_id_str };
+    pub fn launch_with_job_id(cookie: &str, place_id: Option<&str>, job_id: Option<&str>, options: &LaunchOptions) -> Result<(), String> {
+        let place_id = match place_id.map(str::trim).filter(|p| !p.is_empty()) {
+            Some(p) => Some(p.parse::<u64>().map_err(|_| format!("Invalid place ID: {}", p))?),
+            None => None,
+        };
         
-        let launcher_url = if let Some(jid) = job_id {
-            format!(
-                "https://assetgame.roblox.com/game/PlaceLauncher.ashx?request=RequestGameJob&browserTrackerId={}&placeId={}&gameId={}&isPlayTogetherGame=false&isTeleport=true",
-                browser_tracker_id, place_id_num, jid
-            )
-        } else {
-            format!(
-                "https://assetgame.roblox.com/game/PlaceLauncher.ashx?request=RequestGame&browserTrackerId={}&placeId={}&isPlayTogetherGame=false",
-                browser_tracker_id, place_id_num
-            )
+        let mode = match (place_id, job_id) {
+            (Some(place_id), Some(job_id)) => LaunchMode::Job { place_id, job_id: job_id.to_string() },
+            (Some(place_id), None) => LaunchMode::Place { place_id },
+            (None, _) => LaunchMode::App,
         };
         
-        let launch_url = format!(
-            "roblox-player:1+launchmode:play+gameinfo:{}+launchtime:{}+placelauncherurl:{}+browsertrackerid:{}+robloxLocale:en_us+gameLocale:en_us+channel:+LaunchExp:InApp",
-            auth_ticket,
-            chrono::Utc::now().timestamp_millis(),
-            urlencoding::encode(&launcher_url),
-            browser_tracker_id
-        );
-
-        Command::new("cmd")
-            .args(["/C", "start", "", &launch_url])
-            .creation_flags(CREATE_NO_WINDOW)
-            .spawn()
-            .map_err(|e| format!("Failed to launch: {}", e))?;
-
-        Ok(())
-    }
-
-    #[cfg(not(windows))]
-    pub fn set_account_and_launch(_cookie: &str, _place_id: Option<&str>) -> Result<(), String> {
-        Err("Account switching only works on Windows".to_string())
+        Self::launch(cookie, LaunchRequest::new(mode), options)
     }
     
-    #[cfg(windows)]
-    pub fn launch_to_server(cookie: &str, place_id: &str, job_id: &str) -> Result<(), String> {
-        Self::launch_with_job_id(cookie, Some(place_id), Some(job_id), false)
+    pub fn launch_to_server(cookie: &str, place_id: &str, job_id: &str, options: &LaunchOptions) -> Result<(), String> {
+        Self::launch_with_job_id(cookie, Some(place_id), Some(job_id), options)
     }
     
     /// Launch to a VIP/private server with access code and optional link code
-    #[cfg(windows)]
     pub fn launch_to_private_server(
         cookie: &str,
         place_id: &str,
         access_code: &str,
         link_code: Option<&str>,
-        multi_instance: bool,
+        options: &LaunchOptions,
     ) -> Result<(), String> {
-        if !multi_instance {
-            Command::new("taskkill").args(["/F", "/IM", "RobloxPlayerBeta.exe"]).creation_flags(CREATE_NO_WINDOW).output().ok();
-            Command::new("taskkill").args(["/F", "/IM", "Roblox.exe"]).creation_flags(CREATE_NO_WINDOW).output().ok();
-            std::thread::sleep(Duration::from_millis(500));
-        }
-
-        let auth_ticket = Self::get_auth_ticket(cookie)?;
-        let browser_tracker_id: u64 = rand::random::<u64>() % 1_000_000_000_000;
-        
-        let launcher_url = if let Some(lc) = link_code {
-            format!(
-                "https://assetgame.roblox.com/game/PlaceLauncher.ashx?request=RequestPrivateGame&placeId={}&accessCode={}&linkCode={}",
-                place_id, access_code, lc
-            )
-        } else {
-            format!(
-                "https://assetgame.roblox.com/game/PlaceLauncher.ashx?request=RequestPrivateGame&placeId={}&accessCode={}",
-                place_id, access_code
-            )
+        let place_id = place_id.trim().parse::<u64>().map_err(|_| format!("Invalid place ID: {}", place_id))?;
+        let mode = LaunchMode::PrivateServer {
+            place_id,
+            access_code: access_code.to_string(),
+            link_code: link_code.map(|c| c.to_string()),
         };
         
-        let launch_url = format!(
-            "roblox-player:1+launchmode:play+gameinfo:{}+launchtime:{}+placelauncherurl:{}+browsertrackerid:{}+robloxLocale:en_us+gameLocale:en_us+channel:+LaunchExp:InApp",
-            auth_ticket,
-            chrono::Utc::now().timestamp_millis(),
-            urlencoding::encode(&launcher_url),
-            browser_tracker_id
-        );
-
-        Command::new("cmd")
-            .args(["/C", "start", "", &launch_url])
-            .creation_flags(CREATE_NO_WINDOW)
-            .spawn()
-            .map_err(|e| format!("Failed to launch: {}", e))?;
-
-        Ok(())
+        Self::launch(cookie, LaunchRequest::new(mode), options)
     }
     
-    #[cfg(not(windows))]
-    pub fn launch_to_private_server(
-        _cookie: &str,
-        _place_id: &str,
-        _access_code: &str,
-        _link_code: Option<&str>,
-        _multi_instance: bool,
-    ) -> Result<(), String> {
-        Err("Private server joining only works on Windows".to_string())
+    /// Sign the request with a fresh auth ticket and hand its URI to the launcher
+    pub fn launch(cookie: &str, mut request: LaunchRequest, options: &LaunchOptions) -> Result<(), String> {
+        request.auth_ticket = Self::get_auth_ticket(cookie)?;
+        Self::open_request(request, options)
     }
     
-    #[cfg(not(windows))]
-    pub fn launch_to_server(_cookie: &str, _place_id: &str, _job_id: &str) -> Result<(), String> {
-        Err("Server joining only works on Windows".to_string())
+    /// Open an already signed request with the configured locale and channel
+    pub fn open_request(mut request: LaunchRequest, options: &LaunchOptions) -> Result<(), String> {
+        if !options.locale.is_empty() {
+            request.locale = options.locale.clone();
+        }
+        request.channel = options.channel.clone();
+        
+        Self::close_clients(options);
+        let baseline = options.platform.client_pids();
+        options.platform.open_uri(&request.to_uri(), options)?;
+        
+        let job_id = match request.mode {
+            LaunchMode::Job { ref job_id, .. } => Some(job_id.clone()),
+            _ => None,
+        };
+        options.clients.expect(&options.account, request.mode.place_id(), job_id, baseline);
+        Ok(())
     }
     
     async fn get_csrf_token_async(client: &reqwest::Client, cookie: &str) -> Result<String, String> {
@@ -585,6 +657,116 @@ impl RobloxApi {
         })
     }
     
+    /// The account's block list with headshots
+    pub fn get_blocked_users(cookie: &str) -> Result<Vec<BlockedUser>, String> {
+        let cookie = cookie.to_string();
+        
+        let mut blocked = run_async(async move {
+            let client = reqwest::Client::new();
+            let formatted_cookie = format_cookie_str(&cookie);
+            
+            let resp = client
+                .get("https://accountsettings.roblox.com/v1/users/get-detailed-blocked-users")
+                .header("Cookie", format!(".ROBLOSECURITY={}", formatted_cookie))
+                .send()
+                .await
+                .map_err(|e| format!("Request failed: {}", e))?;
+            
+            if !resp.status().is_success() {
+                return Err(format!("Failed: HTTP {}", resp.status()));
+            }
+            
+            let data: serde_json::Value = resp.json().await
+                .map_err(|e| format!("Failed to parse: {}", e))?;
+            
+            Ok(data.get("blockedUsers")
+                .and_then(|v| v.as_array())
+                .map(|arr| arr.iter().map(|u| BlockedUser {
+                    user_id: u.get("userId").and_then(|v| v.as_u64()).unwrap_or(0),
+                    username: u.get("name").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+                    display_name: u.get("displayName").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+                    avatar_url: None,
+                }).collect::<Vec<_>>())
+                .unwrap_or_default())
+        })?;
+        
+        let ids: Vec<u64> = blocked.iter().map(|u| u.user_id).collect();
+        for chunk in ids.chunks(100) {
+            let avatars = Self::get_avatar_thumbnails(chunk).unwrap_or_default();
+            for user in blocked.iter_mut().filter(|u| chunk.contains(&u.user_id)) {
+                user.avatar_url = avatars.get(&user.user_id).cloned();
+            }
+        }
+        
+        Ok(blocked)
+    }
+    
+    /// Current value of every entry in `PRIVACY_SETTINGS`, keyed by field.
+    /// Settings that fail to load are left out.
+    pub fn get_privacy_settings(cookie: &str) -> Result<HashMap<String, String>, String> {
+        let cookie = cookie.to_string();
+        
+        run_async(async move {
+            let client = reqwest::Client::new();
+            let cookie_header = format!(".ROBLOSECURITY={}", format_cookie_str(&cookie));
+            let mut values = HashMap::new();
+            let mut last_error = None;
+            
+            for setting in PRIVACY_SETTINGS {
+                let resp = match client.get(setting.url).header("Cookie", &cookie_header).send().await {
+                    Ok(resp) if resp.status().is_success() => resp,
+                    Ok(resp) => {
+                        last_error = Some(format!("Failed: HTTP {}", resp.status()));
+                        continue;
+                    }
+                    Err(e) => {
+                        last_error = Some(format!("Request failed: {}", e));
+                        continue;
+                    }
+                };
+                
+                if let Ok(data) = resp.json::<serde_json::Value>().await {
+                    if let Some(value) = data.get(setting.field).and_then(|v| v.as_str()) {
+                        values.insert(setting.field.to_string(), value.to_string());
+                    }
+                }
+            }
+            
+            match last_error {
+                Some(e) if values.is_empty() => Err(e),
+                _ => Ok(values),
+            }
+        })
+    }
+    
+    pub fn set_privacy_setting(cookie: &str, setting: &PrivacySetting, value: &str) -> Result<(), String> {
+        let cookie = cookie.to_string();
+        let url = setting.url;
+        let body = serde_json::json!({ setting.field: value });
+        
+        run_async(async move {
+            let client = reqwest::Client::new();
+            let formatted_cookie = format_cookie_str(&cookie);
+            let csrf = Self::get_csrf_token_async(&client, &cookie).await?;
+            
+            let resp = client
+                .post(url)
+                .header("Cookie", format!(".ROBLOSECURITY={}", formatted_cookie))
+                .header("X-CSRF-TOKEN", &csrf)
+                .header("Referer", "https://www.roblox.com/")
+                .json(&body)
+                .send()
+                .await
+                .map_err(|e| format!("Request failed: {}", e))?;
+            
+            if resp.status().is_success() {
+                Ok(())
+            } else {
+                Err(format!("Failed: HTTP {}", resp.status()))
+            }
+        })
+    }
+    
     pub fn send_friend_request(cookie: &str, user_id: u64) -> Result<(), String> {
         let cookie = cookie.to_string();
         
@@ -611,6 +793,100 @@ impl RobloxApi {
         })
     }
     
+    /// Every pending incoming friend request, newest first
+    pub fn get_friend_requests(cookie: &str) -> Result<Vec<FriendRequest>, String> {
+        let cookie = cookie.to_string();
+        
+        run_async(async move {
+            let client = reqwest::Client::new();
+            let cookie_header = format!(".ROBLOSECURITY={}", format_cookie_str(&cookie));
+            let mut requests = Vec::new();
+            let mut cursor = String::new();
+            
+            loop {
+                let resp = client
+                    .get(format!(
+                        "https://friends.roblox.com/v1/my/friends/requests?limit=100&sortOrder=Desc&cursor={}",
+                        urlencoding::encode(&cursor)
+                    ))
+                    .header("Cookie", &cookie_header)
+                    .send()
+                    .await
+                    .map_err(|e| format!("Request failed: {}", e))?;
+                
+                if !resp.status().is_success() {
+                    return Err(format!("Failed: HTTP {}", resp.status()));
+                }
+                
+                let data: serde_json::Value = resp.json().await
+                    .map_err(|e| format!("Failed to parse: {}", e))?;
+                
+                for entry in data.get("data").and_then(|d| d.as_array()).into_iter().flatten() {
+                    requests.push(FriendRequest {
+                        user_id: entry.get("id").and_then(|v| v.as_u64()).unwrap_or(0),
+                        username: entry.get("name").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+                        display_name: entry.get("displayName").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+                        created: entry.get("created").and_then(|v| v.as_str()).map(|s| s.to_string()),
+                        sent_at: entry.get("friendRequest")
+                            .and_then(|r| r.get("sentAt"))
+                            .and_then(|v| v.as_str())
+                            .map(|s| s.to_string()),
+                        mutual_friends: entry.get("mutualFriendsList")
+                            .and_then(|v| v.as_array())
+                            .map(|arr| arr.iter().filter_map(|n| n.as_str().map(|s| s.to_string())).collect())
+                            .unwrap_or_default(),
+                    });
+                }
+                
+                match data.get("nextPageCursor").and_then(|c| c.as_str()) {
+                    Some(next) if !next.is_empty() => cursor = next.to_string(),
+                    _ => break,
+                }
+            }
+            
+            Ok(requests)
+        })
+    }
+    
+    pub fn accept_friend_request(cookie: &str, user_id: u64) -> Result<(), String> {
+        Self::post_with_csrf(cookie, format!("https://friends.roblox.com/v1/users/{}/accept-friend-request", user_id))
+    }
+    
+    pub fn decline_friend_request(cookie: &str, user_id: u64) -> Result<(), String> {
+        Self::post_with_csrf(cookie, format!("https://friends.roblox.com/v1/users/{}/decline-friend-request", user_id))
+    }
+    
+    pub fn decline_all_friend_requests(cookie: &str) -> Result<(), String> {
+        Self::post_with_csrf(cookie, "https://friends.roblox.com/v1/user/friend-requests/decline-all".to_string())
+    }
+    
+    /// Authenticated POST with no body, succeeding on any 2xx
+    fn post_with_csrf(cookie: &str, url: String) -> Result<(), String> {
+        let cookie = cookie.to_string();
+        
+        run_async(async move {
+            let client = reqwest::Client::new();
+            let formatted_cookie = format_cookie_str(&cookie);
+            let csrf = Self::get_csrf_token_async(&client, &cookie).await?;
+            
+            let resp = client
+                .post(&url)
+                .header("Cookie", format!(".ROBLOSECURITY={}", formatted_cookie))
+                .header("X-CSRF-TOKEN", &csrf)
+                .header("Content-Type", "application/json")
+                .header("Referer", "https://www.roblox.com/")
+                .send()
+                .await
+                .map_err(|e| format!("Request failed: {}", e))?;
+            
+            if resp.status().is_success() {
+                Ok(())
+            } else {
+                Err(format!("Failed: HTTP {}", resp.status()))
+            }
+        })
+    }
+    
     pub fn get_user_id_by_username(username: &str) -> Result<u64, String> {
         let username = username.to_string();
         
@@ -802,14 +1078,63 @@ impl RobloxApi {
         })
     }
     
-    pub fn get_inventory_info(user_id: u64) -> Result<u32, String> {
-        let user_id = user_id;
-        
+    /// Every limited item in the user's inventory, following pagination cursors.
+    /// A private inventory answers 403 and comes back as an error.
+    pub fn get_collectibles(user_id: u64) -> Result<Vec<CollectibleItem>, String> {
+        run_async(async move {
+            let client = reqwest::Client::new();
+            let mut items = Vec::new();
+            let mut cursor = String::new();
+            
+            loop {
+                let resp = client
+                    .get(format!(
+                        "https://inventory.roblox.com/v1/users/{}/assets/collectibles?limit=100&sortOrder=Asc&cursor={}",
+                        user_id, urlencoding::encode(&cursor)
+                    ))
+                    .send()
+                    .await
+                    .map_err(|e| format!("Request failed: {}", e))?;
+                
+                if !resp.status().is_success() {
+                    return Err(format!("Failed: HTTP {}", resp.status()));
+                }
+                
+                let data: serde_json::Value = resp.json().await
+                    .map_err(|e| format!("Failed to parse: {}", e))?;
+                
+                if let Some(arr) = data.get("data").and_then(|d| d.as_array()) {
+                    for item in arr {
+                        items.push(CollectibleItem {
+                            user_asset_id: item.get("userAssetId").and_then(|v| v.as_u64()).unwrap_or(0),
+                            asset_id: item.get("assetId").and_then(|v| v.as_u64()).unwrap_or(0),
+                            name: item.get("name").and_then(|v| v.as_str()).unwrap_or("Unknown").to_string(),
+                            serial: item.get("serialNumber").and_then(|v| v.as_u64()),
+                            rap: item.get("recentAveragePrice").and_then(|v| v.as_u64()).unwrap_or(0),
+                        });
+                    }
+                }
+                
+                match data.get("nextPageCursor").and_then(|c| c.as_str()) {
+                    Some(next) if !next.is_empty() => cursor = next.to_string(),
+                    _ => break,
+                }
+            }
+            
+            Ok(items)
+        })
+    }
+    
+    /// Profile details for the account view: creation date, follow counts, groups,
+    /// verified badge and full-body thumbnail. The collectibles count is filled in
+    /// from `get_collectibles` by the caller.
+    pub fn get_account_details(user_id: u64) -> Result<AccountDetails, String> {
         run_async(async move {
             let client = reqwest::Client::new();
+            let mut details = AccountDetails::default();
             
             let resp = client
-                .get(format!("https://inventory.roblox.com/v1/users/{}/assets/collectibles?limit=10", user_id))
+                .get(format!("https://users.roblox.com/v1/users/{}", user_id))
                 .send()
                 .await
                 .map_err(|e| format!("Request failed: {}", e))?;
@@ -818,16 +1143,72 @@ impl RobloxApi {
                 return Err(format!("Failed: HTTP {}", resp.status()));
             }
             
-            let data: serde_json::Value = resp.json().await
+            let user: serde_json::Value = resp.json().await
                 .map_err(|e| format!("Failed to parse: {}", e))?;
+            details.created = user.get("created").and_then(|v| v.as_str()).map(|s| s.to_string());
+            details.has_verified_badge = user.get("hasVerifiedBadge").and_then(|v| v.as_bool()).unwrap_or(false);
             
-            // Return count of items
-            let count = data.get("data")
-                .and_then(|d| d.as_array())
-                .map(|arr| arr.len() as u32)
-                .unwrap_or(0);
+            if let Ok(resp) = client
+                .get(format!("https://friends.roblox.com/v1/users/{}/followers/count", user_id))
+                .send()
+                .await
+            {
+                if let Ok(data) = resp.json::<serde_json::Value>().await {
+                    details.follower_count = data.get("count").and_then(|v| v.as_u64()).unwrap_or(0);
+                }
+            }
+            
+            if let Ok(resp) = client
+                .get(format!("https://friends.roblox.com/v1/users/{}/followings/count", user_id))
+                .send()
+                .await
+            {
+                if let Ok(data) = resp.json::<serde_json::Value>().await {
+                    details.following_count = data.get("count").and_then(|v| v.as_u64()).unwrap_or(0);
+                }
+            }
+            
+            if let Ok(resp) = client
+                .get(format!("https://groups.roblox.com/v2/users/{}/groups/roles", user_id))
+                .send()
+                .await
+            {
+                if let Ok(data) = resp.json::<serde_json::Value>().await {
+                    if let Some(groups) = data.get("data").and_then(|d| d.as_array()) {
+                        for entry in groups {
+                            let group = entry.get("group");
+                            let role = entry.get("role");
+                            details.groups.push(GroupMembership {
+                                group_id: group.and_then(|g| g.get("id")).and_then(|v| v.as_u64()).unwrap_or(0),
+                                name: group.and_then(|g| g.get("name")).and_then(|v| v.as_str()).unwrap_or("Unknown").to_string(),
+                                role: role.and_then(|r| r.get("name")).and_then(|v| v.as_str()).unwrap_or("").to_string(),
+                                rank: role.and_then(|r| r.get("rank")).and_then(|v| v.as_u64()).unwrap_or(0) as u32,
+                            });
+                        }
+                    }
+                }
+            }
+            
+            if let Ok(resp) = client
+                .get(format!(
+                    "https://thumbnails.roblox.com/v1/users/avatar?userIds={}&size=420x420&format=Png&isCircular=false",
+                    user_id
+                ))
+                .send()
+                .await
+            {
+                if let Ok(data) = resp.json::<serde_json::Value>().await {
+                    details.full_body_url = data.get("data")
+                        .and_then(|d| d.as_array())
+                        .and_then(|arr| arr.first())
+                        .filter(|a| a.get("state").and_then(|v| v.as_str()) == Some("Completed"))
+                        .and_then(|a| a.get("imageUrl"))
+                        .and_then(|v| v.as_str())
+                        .map(|s| s.to_string());
+                }
+            }
             
-            Ok(count)
+            Ok(details)
         })
     }
     
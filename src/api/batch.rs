@@ -0,0 +1,74 @@
+use std::sync::mpsc::{channel, Receiver, TryRecvError};
+use std::thread;
+
+/// Runs one blocking job per account on a background thread so the UI keeps drawing,
+/// like the launch queue does for launches. Call `poll` every frame to pick up results.
+pub struct BatchTask<T> {
+    receiver: Receiver<T>,
+    total: usize,
+    done: usize,
+    /// Set if the worker went away early, e.g. a job panicked
+    stopped: bool,
+}
+
+impl<T: Send + 'static> BatchTask<T> {
+    /// Run `work` on each job in order. Dropping the task stops the worker after the current job.
+    pub fn spawn<J: Send + 'static>(jobs: Vec<J>, work: impl Fn(J) -> T + Send + 'static) -> Self {
+        let (sender, receiver) = channel();
+        let total = jobs.len();
+        thread::spawn(move || {
+            for job in jobs {
+                if sender.send(work(job)).is_err() {
+                    return;
+                }
+            }
+        });
+        Self { receiver, total, done: 0, stopped: false }
+    }
+    
+    /// Results that came in since the last poll
+    pub fn poll(&mut self) -> Vec<T> {
+        let mut results = Vec::new();
+        loop {
+            match self.receiver.try_recv() {
+                Ok(result) => results.push(result),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    self.stopped = true;
+                    break;
+                }
+            }
+        }
+        self.done += results.len();
+        results
+    }
+    
+    /// (done, total)
+    pub fn progress(&self) -> (usize, usize) {
+        (self.done, self.total)
+    }
+    
+    pub fn is_finished(&self) -> bool {
+        self.done >= self.total || self.stopped
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_batch_task_runs_every_job() {
+        let mut task = BatchTask::spawn(vec![1, 2, 3], |n| n * 10);
+        let mut results = Vec::new();
+        while !task.is_finished() {
+            results.extend(task.poll());
+            thread::yield_now();
+        }
+        assert_eq!(results, vec![10, 20, 30]);
+        assert_eq!(task.progress(), (3, 3));
+        
+        let empty = BatchTask::spawn(Vec::<u32>::new(), |n| n);
+        assert!(empty.is_finished());
+    }
+}
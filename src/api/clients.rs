@@ -0,0 +1,147 @@
+use crate::platform::Platform;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// How long to wait for a launched client to show up before giving up on it
+const ATTACH_TIMEOUT: Duration = Duration::from_secs(45);
+
+/// A Roblox client started from the manager
+#[derive(Clone, Debug)]
+pub struct ClientProcess {
+    pub account: String,
+    pub pid: u32,
+    pub started: chrono::DateTime<chrono::Local>,
+    pub place_id: Option<u64>,
+    pub job_id: Option<String>,
+}
+
+/// A launch whose client process hasn't been seen yet
+#[derive(Debug)]
+struct PendingLaunch {
+    account: String,
+    place_id: Option<u64>,
+    job_id: Option<String>,
+    /// Clients that were already running when the launch started
+    baseline: HashSet<u32>,
+    since: Instant,
+}
+
+#[derive(Debug, Default)]
+struct Tracker {
+    clients: Vec<ClientProcess>,
+    pending: Vec<PendingLaunch>,
+    exited: Vec<ClientProcess>,
+}
+
+/// Roblox clients started from the manager. Owned by the app and shared with launches
+/// through `LaunchOptions`, so every app (and every test) has its own.
+#[derive(Debug, Default)]
+pub struct ClientTracker {
+    tracker: Mutex<Tracker>,
+    scanning: AtomicBool,
+}
+
+impl ClientTracker {
+    /// Remember that `account` is about to get a client. `baseline` is the result of
+    /// `Platform::client_pids` taken just before the launch, so clients of other accounts aren't claimed.
+    pub fn expect(&self, account: &str, place_id: Option<u64>, job_id: Option<String>, baseline: HashSet<u32>) {
+        let mut tracker = self.tracker.lock().unwrap();
+        tracker.pending.retain(|p| p.account != account);
+        tracker.pending.push(PendingLaunch {
+            account: account.to_string(),
+            place_id,
+            job_id,
+            baseline,
+            since: Instant::now(),
+        });
+    }
+    
+    /// The tracked client for `account`, if it's still running
+    pub fn client_for(&self, account: &str) -> Option<ClientProcess> {
+        self.tracker.lock().unwrap().clients.iter().find(|c| c.account == account).cloned()
+    }
+    
+    pub fn running_count(&self) -> usize {
+        self.tracker.lock().unwrap().clients.len()
+    }
+    
+    /// True while a launch is waiting for its client or any client is tracked
+    pub fn has_work(&self) -> bool {
+        let tracker = self.tracker.lock().unwrap();
+        !tracker.clients.is_empty() || !tracker.pending.is_empty()
+    }
+    
+    /// Clients that exited since the last call
+    pub fn take_exited(&self) -> Vec<ClientProcess> {
+        std::mem::take(&mut self.tracker.lock().unwrap().exited)
+    }
+    
+    /// Scan the process list on a background thread and update the tracker.
+    /// Does nothing if a scan is already running.
+    pub fn refresh_in_background(self: &Arc<Self>, platform: Arc<dyn Platform>) {
+        if self.scanning.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let this = self.clone();
+        std::thread::spawn(move || {
+            this.update(&platform.client_pids());
+            this.scanning.store(false, Ordering::SeqCst);
+        });
+    }
+    
+    /// Match the current client PIDs against tracked clients and waiting launches
+    pub fn update(&self, pids: &HashSet<u32>) {
+        let mut tracker = self.tracker.lock().unwrap();
+        let tracker = &mut *tracker;
+        
+        let (alive, gone): (Vec<_>, Vec<_>) = std::mem::take(&mut tracker.clients)
+            .into_iter()
+            .partition(|c| pids.contains(&c.pid));
+        tracker.clients = alive;
+        tracker.exited.extend(gone);
+        
+        // Hand new processes to waiting launches, oldest launch first
+        let mut claimed: HashSet<u32> = tracker.clients.iter().map(|c| c.pid).collect();
+        let mut new_pids: Vec<u32> = pids.iter().copied().collect();
+        new_pids.sort_unstable();
+        
+        tracker.pending.sort_by_key(|p| p.since);
+        tracker.pending.retain(|launch| {
+            let found = new_pids.iter()
+                .find(|pid| !claimed.contains(pid) && !launch.baseline.contains(pid))
+                .copied();
+            match found {
+                Some(pid) => {
+                    claimed.insert(pid);
+                    tracker.clients.push(ClientProcess {
+                        account: launch.account.clone(),
+                        pid,
+                        started: chrono::Local::now(),
+                        place_id: launch.place_id,
+                        job_id: launch.job_id.clone(),
+                    });
+                    false
+                }
+                None => launch.since.elapsed() < ATTACH_TIMEOUT,
+            }
+        });
+    }
+    
+    /// Close `account`'s client, if one is tracked
+    pub fn close(&self, account: &str, platform: &dyn Platform) -> Result<bool, String> {
+        let Some(client) = self.client_for(account) else {
+            return Ok(false);
+        };
+        platform.kill_process(client.pid)?;
+        self.tracker.lock().unwrap().clients.retain(|c| c.pid != client.pid);
+        Ok(true)
+    }
+    
+    /// Close every tracked client, returning how many were closed
+    pub fn close_all(&self, platform: &dyn Platform) -> usize {
+        let clients = std::mem::take(&mut self.tracker.lock().unwrap().clients);
+        clients.iter().filter(|c| platform.kill_process(c.pid).is_ok()).count()
+    }
+}
@@ -0,0 +1,665 @@
+use std::collections::{HashSet, VecDeque};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::{fetch_servers, LaunchMode, LaunchOptions, LaunchRequest, LinkTarget, RobloxApi, ServerData};
+use crate::account::UserPresenceType;
+
+/// Tries per account before an auth ticket failure counts as a failed launch
+const TICKET_ATTEMPTS: u32 = 3;
+/// How long to wait for the first account of a together launch to show up in game
+const JOIN_TIMEOUT: Duration = Duration::from_secs(90);
+/// Server list pages to search for the first account's server
+const SERVER_PAGES: usize = 5;
+
+/// Turns a cookie into an auth ticket; tests swap in one that doesn't touch the network
+pub type TicketSource = fn(&str) -> Result<String, String>;
+/// Turns a link target into a launch mode with a cookie; tests swap this one out too
+pub type LinkSource = fn(&LinkTarget, &str) -> Result<LaunchMode, String>;
+
+/// Progress of one queued launch
+#[derive(Clone, Debug, PartialEq)]
+pub enum LaunchState {
+    Queued,
+    /// Turning a share link or link code into an access code
+    ResolvingLink,
+    /// Asking Roblox for an auth ticket, 1-based
+    FetchingTicket { attempt: u32 },
+    /// Together launch waiting for the first account to get into a server
+    WaitingForServer,
+    Launched,
+    Failed(String),
+    Cancelled,
+}
+
+impl LaunchState {
+    pub fn is_finished(&self) -> bool {
+        matches!(self, LaunchState::Launched | LaunchState::Failed(_) | LaunchState::Cancelled)
+    }
+}
+
+/// One account to launch
+#[derive(Clone, Debug)]
+pub struct QueueJob {
+    pub account: String,
+    pub cookie: String,
+    /// Needed to find the server of the first account in a together launch
+    pub user_id: Option<u64>,
+    /// Private server links are resolved with this job's cookie when its turn comes
+    pub target: LinkTarget,
+    pub options: LaunchOptions,
+}
+
+#[derive(Clone, Debug)]
+pub struct QueueItem {
+    pub job: QueueJob,
+    pub state: LaunchState,
+}
+
+/// Accounts queued with `enqueue_together`. Whichever launches first picks the server.
+struct Group {
+    members: HashSet<usize>,
+    /// Where the rest go, once the first account is in game
+    server: Option<LaunchMode>,
+}
+
+/// State shared with the worker. `running` is only changed with `pending` locked,
+/// so a job pushed while the worker is winding down is never left behind.
+struct Shared {
+    pending: VecDeque<(usize, QueueJob)>,
+    groups: Vec<Group>,
+    delay: Duration,
+    running: bool,
+    paused: bool,
+    /// Set while the worker is actually waiting on `paused`
+    holding: bool,
+    cancelled: bool,
+    tickets: TicketSource,
+    links: LinkSource,
+    /// Wait before the first ticket retry, growing with each attempt
+    retry_backoff: Duration,
+}
+
+impl Default for Shared {
+    fn default() -> Self {
+        Self {
+            pending: VecDeque::new(),
+            groups: Vec::new(),
+            delay: Duration::ZERO,
+            running: false,
+            paused: false,
+            holding: false,
+            cancelled: false,
+            tickets: RobloxApi::get_auth_ticket,
+            links: LinkTarget::launch_mode,
+            retry_backoff: Duration::from_secs(2),
+        }
+    }
+}
+
+/// Launches accounts one after another on a background thread so the UI keeps
+/// drawing. Call `poll` every frame to pick up progress.
+pub struct LaunchQueue {
+    items: Vec<QueueItem>,
+    shared: Arc<Mutex<Shared>>,
+    event_sender: Sender<(usize, LaunchState)>,
+    event_receiver: Receiver<(usize, LaunchState)>,
+    was_active: bool,
+}
+
+impl Default for LaunchQueue {
+    fn default() -> Self {
+        let (event_sender, event_receiver) = channel();
+        Self {
+            items: Vec::new(),
+            shared: Arc::new(Mutex::new(Shared::default())),
+            event_sender,
+            event_receiver,
+            was_active: false,
+        }
+    }
+}
+
+impl LaunchQueue {
+    /// Queue that gets tickets and link modes from the given sources and retries without waiting
+    #[cfg(test)]
+    fn with_sources(tickets: TicketSource, links: LinkSource) -> Self {
+        let queue = Self::default();
+        {
+            let mut shared = queue.shared.lock().unwrap();
+            shared.tickets = tickets;
+            shared.links = links;
+            shared.retry_backoff = Duration::ZERO;
+        }
+        queue
+    }
+    
+    /// Add jobs to the end of the queue, waiting `delay` between launches
+    pub fn enqueue(&mut self, jobs: Vec<QueueJob>, delay: Duration) {
+        self.push(jobs, delay, false);
+    }
+    
+    /// Like `enqueue`, but once the first account is in game the rest join its server,
+    /// or another one with room for all of them
+    pub fn enqueue_together(&mut self, jobs: Vec<QueueJob>, delay: Duration) {
+        self.push(jobs, delay, true);
+    }
+    
+    fn push(&mut self, jobs: Vec<QueueJob>, delay: Duration, together: bool) {
+        self.apply_events();
+        if !self.is_active() {
+            self.items.retain(|item| !item.state.is_finished());
+            self.shared.lock().unwrap().groups.clear();
+        }
+        
+        let mut shared = self.shared.lock().unwrap();
+        shared.delay = delay;
+        shared.cancelled = false;
+        let mut members = HashSet::new();
+        for job in jobs {
+            members.insert(self.items.len());
+            shared.pending.push_back((self.items.len(), job.clone()));
+            self.items.push(QueueItem { job, state: LaunchState::Queued });
+        }
+        if together && members.len() > 1 {
+            shared.groups.push(Group { members, server: None });
+        }
+        
+        if !shared.running && !shared.pending.is_empty() {
+            shared.running = true;
+            let worker_shared = self.shared.clone();
+            let events = self.event_sender.clone();
+            thread::spawn(move || run_worker(worker_shared, events));
+        }
+        self.was_active = true;
+    }
+    
+    /// Apply progress from the worker. Returns true once when a run has finished.
+    pub fn poll(&mut self) -> bool {
+        // The worker sends its last update before it stops, so check first and drain after
+        let active = self.is_active();
+        self.apply_events();
+        
+        let finished = self.was_active && !active;
+        if finished {
+            self.was_active = false;
+        }
+        finished
+    }
+    
+    fn apply_events(&mut self) {
+        for (idx, state) in self.event_receiver.try_iter() {
+            if let Some(item) = self.items.get_mut(idx) {
+                item.state = state;
+            }
+        }
+    }
+    
+    pub fn items(&self) -> &[QueueItem] {
+        &self.items
+    }
+    
+    /// True while the worker is running or has jobs left
+    pub fn is_active(&self) -> bool {
+        let shared = self.shared.lock().unwrap();
+        shared.running || !shared.pending.is_empty()
+    }
+    
+    pub fn is_paused(&self) -> bool {
+        self.shared.lock().unwrap().paused
+    }
+    
+    /// True once a pause has taken effect and nothing more will launch until resumed
+    pub fn is_holding(&self) -> bool {
+        self.shared.lock().unwrap().holding
+    }
+    
+    /// Hold off on the next launch; the current one still finishes
+    pub fn set_paused(&self, paused: bool) {
+        self.shared.lock().unwrap().paused = paused;
+    }
+    
+    /// Drop everything still waiting and stop retrying the current account
+    pub fn cancel(&self) {
+        let mut shared = self.shared.lock().unwrap();
+        shared.cancelled = true;
+        shared.paused = false;
+    }
+    
+    /// Queue every failed launch again once the current run is over. Failed members of a
+    /// together launch go to the server their group ended up in, or launch together again
+    /// if none of them got in.
+    pub fn retry_failed(&mut self, delay: Duration) {
+        if self.is_active() {
+            return;
+        }
+        let groups = std::mem::take(&mut self.shared.lock().unwrap().groups);
+        let mut alone = Vec::new();
+        let mut together: Vec<Vec<QueueJob>> = vec![Vec::new(); groups.len()];
+        for (idx, item) in self.items.iter().enumerate() {
+            if !matches!(item.state, LaunchState::Failed(_)) {
+                continue;
+            }
+            let mut job = item.job.clone();
+            match groups.iter().position(|g| g.members.contains(&idx)) {
+                Some(g) => match &groups[g].server {
+                    Some(server) => {
+                        job.target = LinkTarget::Launch(server.clone());
+                        alone.push(job);
+                    }
+                    None => together[g].push(job),
+                },
+                None => alone.push(job),
+            }
+        }
+        
+        if !alone.is_empty() {
+            self.enqueue(alone, delay);
+        }
+        for jobs in together.into_iter().filter(|jobs| !jobs.is_empty()) {
+            self.enqueue_together(jobs, delay);
+        }
+    }
+    
+    /// Forget finished items; only possible while nothing is running
+    pub fn clear_finished(&mut self) {
+        if !self.is_active() {
+            self.items.retain(|item| !item.state.is_finished());
+        }
+    }
+    
+    /// (launched, failed, total)
+    pub fn counts(&self) -> (usize, usize, usize) {
+        let launched = self.items.iter().filter(|i| i.state == LaunchState::Launched).count();
+        let failed = self.items.iter().filter(|i| matches!(i.state, LaunchState::Failed(_))).count();
+        (launched, failed, self.items.len())
+    }
+}
+
+fn run_worker(shared: Arc<Mutex<Shared>>, events: Sender<(usize, LaunchState)>) {
+    let mut first = true;
+    
+    loop {
+        // Wait out the delay between launches, then any pause
+        if !first {
+            let delay = shared.lock().unwrap().delay;
+            wait(&shared, delay);
+        }
+        loop {
+            {
+                let mut shared = shared.lock().unwrap();
+                shared.holding = shared.paused && !shared.cancelled;
+                if !shared.holding {
+                    break;
+                }
+            }
+            thread::sleep(Duration::from_millis(100));
+        }
+        
+        let next = {
+            let mut shared = shared.lock().unwrap();
+            if shared.cancelled {
+                for (idx, _) in shared.pending.drain(..) {
+                    let _ = events.send((idx, LaunchState::Cancelled));
+                }
+                shared.cancelled = false;
+            }
+            match shared.pending.pop_front() {
+                Some(next) => next,
+                None => {
+                    shared.running = false;
+                    return;
+                }
+            }
+        };
+        
+        let (idx, job) = next;
+        
+        // Follow the group's server if one has been found; otherwise this account leads
+        let (group, server) = {
+            let shared = shared.lock().unwrap();
+            let group = shared.groups.iter().position(|g| g.members.contains(&idx));
+            (group, group.and_then(|g| shared.groups[g].server.clone()))
+        };
+        let leading = group.is_some() && server.is_none();
+        let mode = match server {
+            Some(mode) => Ok(mode),
+            None => resolve_target(&shared, &events, idx, &job),
+        };
+        
+        let state = match &mode {
+            Ok(mode) => launch_job(&shared, &events, idx, &job, mode.clone()),
+            Err(e) => LaunchState::Failed(e.clone()),
+        };
+        let launched = state == LaunchState::Launched;
+        let _ = events.send((idx, state));
+        first = false;
+        
+        if let (Some(g), true, true, Ok(mode)) = (group, leading, launched, mode) {
+            // Private servers, job IDs and follows already name the server to join
+            let LaunchMode::Place { .. } = mode else {
+                shared.lock().unwrap().groups[g].server = Some(mode);
+                continue;
+            };
+            let followers: Vec<usize> = {
+                let shared = shared.lock().unwrap();
+                shared.pending.iter()
+                    .map(|(i, _)| *i)
+                    .filter(|i| shared.groups[g].members.contains(i))
+                    .collect()
+            };
+            for follower in &followers {
+                let _ = events.send((*follower, LaunchState::WaitingForServer));
+            }
+            
+            // If the server can't be found the rest launch as queued
+            let server = find_server(&shared, &job, &mode, followers.len() as u32).unwrap_or(mode);
+            shared.lock().unwrap().groups[g].server = Some(server);
+            for follower in &followers {
+                let _ = events.send((*follower, LaunchState::Queued));
+            }
+        }
+    }
+}
+
+/// Where `job` launches to, resolving a private server link with its cookie
+fn resolve_target(shared: &Arc<Mutex<Shared>>, events: &Sender<(usize, LaunchState)>, idx: usize, job: &QueueJob) -> Result<LaunchMode, String> {
+    if let LinkTarget::Launch(mode) = &job.target {
+        return Ok(mode.clone());
+    }
+    let links = shared.lock().unwrap().links;
+    let _ = events.send((idx, LaunchState::ResolvingLink));
+    links(&job.target, &job.cookie)
+}
+
+fn launch_job(shared: &Arc<Mutex<Shared>>, events: &Sender<(usize, LaunchState)>, idx: usize, job: &QueueJob, mode: LaunchMode) -> LaunchState {
+    let (tickets, backoff) = {
+        let shared = shared.lock().unwrap();
+        (shared.tickets, shared.retry_backoff)
+    };
+    let mut attempt = 1;
+    let ticket = loop {
+        let _ = events.send((idx, LaunchState::FetchingTicket { attempt }));
+        match tickets(&job.cookie) {
+            Ok(ticket) => break ticket,
+            Err(e) if attempt >= TICKET_ATTEMPTS => return LaunchState::Failed(e),
+            Err(_) => {
+                wait(shared, backoff * attempt);
+                if is_cancelled(shared) {
+                    return LaunchState::Cancelled;
+                }
+                attempt += 1;
+            }
+        }
+    };
+    
+    let mut request = LaunchRequest::new(mode);
+    request.auth_ticket = ticket;
+    match RobloxApi::open_request(request, &job.options) {
+        Ok(_) => LaunchState::Launched,
+        Err(e) => LaunchState::Failed(e),
+    }
+}
+
+/// Wait for `lead` to get into a game, then pick a server with room for `needed` more
+fn find_server(shared: &Arc<Mutex<Shared>>, lead: &QueueJob, mode: &LaunchMode, needed: u32) -> Option<LaunchMode> {
+    let user_id = lead.user_id?;
+    let since = Instant::now();
+    
+    let (place_id, job_id) = loop {
+        wait(shared, Duration::from_secs(3));
+        if is_cancelled(shared) || since.elapsed() > JOIN_TIMEOUT {
+            return None;
+        }
+        let presence = RobloxApi::get_presence_as(Some(&lead.cookie), &[user_id]).ok()
+            .and_then(|mut p| p.remove(&user_id));
+        if let Some(p) = presence.filter(|p| p.presence_type == UserPresenceType::InGame) {
+            if let (Some(place_id), Some(job_id)) = (p.place_id.or(mode.place_id()), p.game_id) {
+                break (place_id, job_id);
+            }
+        }
+    };
+    
+    let mut servers = Vec::new();
+    let mut cursor: Option<String> = None;
+    for _ in 0..SERVER_PAGES {
+        let Ok(page) = fetch_servers(&place_id.to_string(), cursor.as_deref()) else { break };
+        let found = page.data.iter().any(|s| s.id == job_id);
+        servers.extend(page.data);
+        cursor = page.next_page_cursor;
+        if found || cursor.is_none() {
+            break;
+        }
+    }
+    
+    let job_id = choose_server(&servers, &job_id, needed);
+    Some(LaunchMode::Job { place_id, job_id })
+}
+
+/// The lead's server if it has room for `needed` more, otherwise the first listed
+/// server that does. A server that isn't listed (or no better choice) keeps the lead's.
+fn choose_server(servers: &[ServerData], lead_job: &str, needed: u32) -> String {
+    let has_room = |s: &ServerData| s.max_players.saturating_sub(s.playing) >= needed;
+    match servers.iter().find(|s| s.id == lead_job) {
+        Some(lead) if !has_room(lead) => servers.iter()
+            .find(|s| has_room(s))
+            .map_or_else(|| lead_job.to_string(), |s| s.id.clone()),
+        _ => lead_job.to_string(),
+    }
+}
+
+fn is_cancelled(shared: &Arc<Mutex<Shared>>) -> bool {
+    shared.lock().unwrap().cancelled
+}
+
+/// Sleep for `duration`, waking early on cancel
+fn wait(shared: &Arc<Mutex<Shared>>, duration: Duration) {
+    let until = Instant::now() + duration;
+    while Instant::now() < until && !is_cancelled(shared) {
+        thread::sleep(Duration::from_millis(100));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::LauncherBackend;
+    use crate::platform::FakePlatform;
+    
+    fn fake_tickets(cookie: &str) -> Result<String, String> {
+        if cookie == "expired" {
+            Err("Cookie is invalid or expired".to_string())
+        } else {
+            Ok(format!("ticket-{}", cookie))
+        }
+    }
+    
+    fn fake_links(target: &LinkTarget, cookie: &str) -> Result<LaunchMode, String> {
+        match target {
+            LinkTarget::Share { code } if code != "gone" => Ok(LaunchMode::PrivateServer {
+                place_id: 1818,
+                access_code: format!("{}-for-{}", code, cookie),
+                link_code: None,
+            }),
+            _ => Err("Share link has expired or is no longer valid".to_string()),
+        }
+    }
+    
+    fn job(platform: &Arc<FakePlatform>, account: &str, cookie: &str) -> QueueJob {
+        QueueJob {
+            account: account.to_string(),
+            cookie: cookie.to_string(),
+            user_id: None,
+            target: LinkTarget::Launch(LaunchMode::Place { place_id: 1818 }),
+            options: LaunchOptions {
+                multi_instance: true,
+                account: account.to_string(),
+                backend: LauncherBackend::default(),
+                locale: String::new(),
+                channel: String::new(),
+                platform: platform.clone(),
+                clients: Default::default(),
+            },
+        }
+    }
+    
+    /// Poll until the worker has stopped, failing the test if it never does
+    fn run_to_end(queue: &mut LaunchQueue) {
+        wait_for(queue, "queue never finished", |queue| queue.poll());
+    }
+    
+    fn wait_for(queue: &mut LaunchQueue, failure: &str, mut done: impl FnMut(&mut LaunchQueue) -> bool) {
+        let until = Instant::now() + Duration::from_secs(5);
+        while !done(queue) {
+            assert!(Instant::now() < until, "{}", failure);
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+    
+    fn states(queue: &LaunchQueue) -> Vec<(String, LaunchState)> {
+        queue.items().iter().map(|item| (item.job.account.clone(), item.state.clone())).collect()
+    }
+    
+    #[test]
+    fn test_queue_launches_in_order_and_retries_failures() {
+        let platform = Arc::new(FakePlatform::starting_at(7100));
+        let mut queue = LaunchQueue::with_sources(fake_tickets, fake_links);
+        queue.enqueue(vec![
+            job(&platform, "queue_a", "good"),
+            job(&platform, "queue_b", "expired"),
+            job(&platform, "queue_c", "good"),
+        ], Duration::ZERO);
+        run_to_end(&mut queue);
+        
+        assert_eq!(states(&queue), vec![
+            ("queue_a".to_string(), LaunchState::Launched),
+            ("queue_b".to_string(), LaunchState::Failed("Cookie is invalid or expired".to_string())),
+            ("queue_c".to_string(), LaunchState::Launched),
+        ]);
+        assert_eq!(queue.counts(), (2, 1, 3));
+        assert_eq!(platform.state().opened.len(), 2);
+        
+        // Only the failure goes again, and finished items make way for it
+        queue.retry_failed(Duration::ZERO);
+        assert_eq!(queue.items().len(), 1);
+        assert_eq!(queue.items()[0].job.account, "queue_b");
+        run_to_end(&mut queue);
+        assert_eq!(queue.counts(), (0, 1, 1));
+        assert_eq!(platform.state().opened.len(), 2);
+    }
+    
+    #[test]
+    fn test_queue_pause_and_cancel() {
+        let platform = Arc::new(FakePlatform::starting_at(7200));
+        let mut queue = LaunchQueue::with_sources(fake_tickets, fake_links);
+        
+        queue.set_paused(true);
+        queue.enqueue(vec![job(&platform, "paused_a", "good")], Duration::ZERO);
+        // Jobs added while running are numbered after the existing ones
+        queue.enqueue(vec![job(&platform, "paused_b", "good")], Duration::ZERO);
+        wait_for(&mut queue, "pause never took effect", |queue| queue.is_holding());
+        queue.poll();
+        assert!(platform.state().opened.is_empty());
+        assert!(queue.items().iter().all(|item| item.state == LaunchState::Queued));
+        
+        queue.set_paused(false);
+        run_to_end(&mut queue);
+        assert_eq!(states(&queue), vec![
+            ("paused_a".to_string(), LaunchState::Launched),
+            ("paused_b".to_string(), LaunchState::Launched),
+        ]);
+        
+        // Cancelling drains everything that hasn't started
+        queue.set_paused(true);
+        queue.enqueue(vec![job(&platform, "cancel_a", "good"), job(&platform, "cancel_b", "good")], Duration::ZERO);
+        assert_eq!(queue.items().len(), 2);
+        queue.cancel();
+        run_to_end(&mut queue);
+        assert_eq!(states(&queue), vec![
+            ("cancel_a".to_string(), LaunchState::Cancelled),
+            ("cancel_b".to_string(), LaunchState::Cancelled),
+        ]);
+        assert!(!queue.is_paused());
+        assert_eq!(platform.state().opened.len(), 2);
+    }
+    
+    #[test]
+    fn test_queue_resolves_links_per_account() {
+        let platform = Arc::new(FakePlatform::starting_at(7300));
+        let mut queue = LaunchQueue::with_sources(fake_tickets, fake_links);
+        let share = |code: &str| LinkTarget::Share { code: code.to_string() };
+        let mut first = job(&platform, "link_a", "alpha");
+        first.target = share("abc");
+        let mut second = job(&platform, "link_b", "beta");
+        second.target = share("gone");
+        queue.enqueue(vec![first, second], Duration::ZERO);
+        run_to_end(&mut queue);
+        
+        assert_eq!(states(&queue), vec![
+            ("link_a".to_string(), LaunchState::Launched),
+            ("link_b".to_string(), LaunchState::Failed("Share link has expired or is no longer valid".to_string())),
+        ]);
+        let opened = platform.state().opened.clone();
+        assert_eq!(opened.len(), 1);
+        assert!(opened[0].contains("abc-for-alpha"));
+    }
+    
+    #[test]
+    fn test_retry_keeps_together_groups() {
+        let platform = Arc::new(FakePlatform::starting_at(7400));
+        let mut queue = LaunchQueue::with_sources(fake_tickets, fake_links);
+        let share = LinkTarget::Share { code: "abc".to_string() };
+        let mut lead = job(&platform, "group_lead", "good");
+        lead.target = share.clone();
+        let mut follower = job(&platform, "group_follower", "expired");
+        follower.target = share.clone();
+        queue.enqueue_together(vec![lead, follower], Duration::ZERO);
+        queue.enqueue_together(vec![job(&platform, "stuck_a", "expired"), job(&platform, "stuck_b", "expired")], Duration::ZERO);
+        run_to_end(&mut queue);
+        assert_eq!(queue.counts(), (1, 3, 4));
+        
+        // The follower goes straight to the lead's server; the group that never got in stays a group
+        queue.retry_failed(Duration::ZERO);
+        let server = LaunchMode::PrivateServer { place_id: 1818, access_code: "abc-for-good".to_string(), link_code: None };
+        assert_eq!(queue.items()[0].job.target, LinkTarget::Launch(server));
+        let groups: Vec<HashSet<usize>> = queue.shared.lock().unwrap().groups.iter().map(|g| g.members.clone()).collect();
+        assert_eq!(groups, vec![HashSet::from([1, 2])]);
+        run_to_end(&mut queue);
+    }
+    
+    #[test]
+    fn test_group_follows_private_server_without_waiting() {
+        let platform = Arc::new(FakePlatform::starting_at(7500));
+        let mut queue = LaunchQueue::with_sources(fake_tickets, fake_links);
+        let mut lead = job(&platform, "vip_lead", "lead");
+        lead.target = LinkTarget::Share { code: "vip".to_string() };
+        // A lead with a user ID would be watched for a public server
+        lead.user_id = Some(1);
+        queue.enqueue_together(vec![lead, job(&platform, "vip_follower", "follower")], Duration::ZERO);
+        
+        let started = Instant::now();
+        run_to_end(&mut queue);
+        assert!(started.elapsed() < Duration::from_secs(2));
+        assert_eq!(queue.counts(), (2, 0, 2));
+        let opened = platform.state().opened.clone();
+        assert_eq!(opened.len(), 2);
+        assert!(opened.iter().all(|uri| uri.contains("vip-for-lead")));
+    }
+    
+    fn server(id: &str, playing: u32, max_players: u32) -> ServerData {
+        ServerData { id: id.to_string(), playing, max_players, ..Default::default() }
+    }
+    
+    #[test]
+    fn test_choose_server() {
+        let servers = vec![server("full", 9, 10), server("roomy", 2, 10), server("lead", 6, 10)];
+        assert_eq!(choose_server(&servers, "lead", 4), "lead");
+        assert_eq!(choose_server(&servers, "lead", 5), "roomy");
+        assert_eq!(choose_server(&servers, "full", 3), "roomy");
+        // Nowhere better to go, or the lead's server isn't listed
+        assert_eq!(choose_server(&servers, "lead", 9), "lead");
+        assert_eq!(choose_server(&servers, "private", 20), "private");
+    }
+}
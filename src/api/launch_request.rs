@@ -0,0 +1,247 @@
+/// Where a launch should put the player
+#[derive(Clone, Debug, PartialEq)]
+pub enum LaunchMode {
+    /// Open the client without joining a game
+    App,
+    /// Any public server of the place
+    Place { place_id: u64 },
+    /// A specific server by its job ID
+    Job { place_id: u64, job_id: String },
+    /// A private (VIP) server, with the share-link code when joining through one
+    PrivateServer { place_id: u64, access_code: String, link_code: Option<String> },
+    /// Whatever server the user is currently in
+    FollowUser { user_id: u64 },
+    /// A play-together session started from a chat conversation
+    #[allow(dead_code)]
+    PlayTogether { place_id: u64, conversation_id: String },
+}
+
+/// Everything that goes into a `roblox-player:` URI
+#[derive(Clone, Debug, PartialEq)]
+pub struct LaunchRequest {
+    pub mode: LaunchMode,
+    pub auth_ticket: String,
+    /// Milliseconds since the Unix epoch
+    pub launch_time: i64,
+    pub browser_tracker_id: u64,
+    /// Sent as both `robloxLocale` and `gameLocale`
+    pub locale: String,
+    /// Client release channel, empty for production
+    pub channel: String,
+}
+
+const PLACE_LAUNCHER: &str = "https://assetgame.roblox.com/game/PlaceLauncher.ashx";
+
+impl LaunchMode {
+    /// The place being joined, when known up front
+    pub fn place_id(&self) -> Option<u64> {
+        match *self {
+            LaunchMode::Place { place_id }
+            | LaunchMode::Job { place_id, .. }
+            | LaunchMode::PrivateServer { place_id, .. }
+            | LaunchMode::PlayTogether { place_id, .. } => Some(place_id),
+            LaunchMode::App | LaunchMode::FollowUser { .. } => None,
+        }
+    }
+}
+
+impl LaunchRequest {
+    pub const DEFAULT_LOCALE: &'static str = "en_us";
+    
+    /// A request for `mode` stamped with the current time and a fresh tracker ID.
+    /// The auth ticket is filled in just before launching.
+    pub fn new(mode: LaunchMode) -> Self {
+        Self {
+            mode,
+            auth_ticket: String::new(),
+            launch_time: chrono::Utc::now().timestamp_millis(),
+            browser_tracker_id: rand::random::<u64>() % 1_000_000_000_000,
+            locale: Self::DEFAULT_LOCALE.to_string(),
+            channel: String::new(),
+        }
+    }
+    
+    /// The `PlaceLauncher.ashx` URL the client calls to find its server; `None` for `App`
+    pub fn place_launcher_url(&self) -> Option<String> {
+        let params: Vec<(&str, String)> = match self.mode {
+            LaunchMode::App => return None,
+            LaunchMode::Place { place_id } => vec![
+                ("request", "RequestGame".to_string()),
+                ("browserTrackerId", self.browser_tracker_id.to_string()),
+                ("placeId", place_id.to_string()),
+                ("isPlayTogetherGame", "false".to_string()),
+            ],
+            LaunchMode::Job { place_id, ref job_id } => vec![
+                ("request", "RequestGameJob".to_string()),
+                ("browserTrackerId", self.browser_tracker_id.to_string()),
+                ("placeId", place_id.to_string()),
+                ("gameId", job_id.clone()),
+                ("isPlayTogetherGame", "false".to_string()),
+                ("isTeleport", "true".to_string()),
+            ],
+            LaunchMode::PrivateServer { place_id, ref access_code, ref link_code } => {
+                let mut params = vec![
+                    ("request", "RequestPrivateGame".to_string()),
+                    ("browserTrackerId", self.browser_tracker_id.to_string()),
+                    ("placeId", place_id.to_string()),
+                    ("accessCode", access_code.clone()),
+                ];
+                if let Some(link_code) = link_code {
+                    params.push(("linkCode", link_code.clone()));
+                }
+                params
+            }
+            LaunchMode::FollowUser { user_id } => vec![
+                ("request", "RequestFollowUser".to_string()),
+                ("browserTrackerId", self.browser_tracker_id.to_string()),
+                ("userId", user_id.to_string()),
+            ],
+            LaunchMode::PlayTogether { place_id, ref conversation_id } => vec![
+                ("request", "RequestPlayTogetherGame".to_string()),
+                ("browserTrackerId", self.browser_tracker_id.to_string()),
+                ("placeId", place_id.to_string()),
+                ("conversationId", conversation_id.clone()),
+                ("isPlayTogetherGame", "true".to_string()),
+            ],
+        };
+        
+        let query: Vec<String> = params.iter()
+            .map(|(key, value)| format!("{}={}", key, urlencoding::encode(value)))
+            .collect();
+        Some(format!("{}?{}", PLACE_LAUNCHER, query.join("&")))
+    }
+    
+    pub fn to_uri(&self) -> String {
+        let mut parts = vec![
+            "roblox-player:1".to_string(),
+            format!("launchmode:{}", if self.mode == LaunchMode::App { "app" } else { "play" }),
+            format!("gameinfo:{}", self.auth_ticket),
+            format!("launchtime:{}", self.launch_time),
+        ];
+        if let Some(url) = self.place_launcher_url() {
+            parts.push(format!("placelauncherurl:{}", urlencoding::encode(&url)));
+        }
+        parts.push(format!("browsertrackerid:{}", self.browser_tracker_id));
+        parts.push(format!("robloxLocale:{}", self.locale));
+        parts.push(format!("gameLocale:{}", self.locale));
+        parts.push(format!("channel:{}", self.channel));
+        parts.push("LaunchExp:InApp".to_string());
+        parts.join("+")
+    }
+    
+    /// Read a `roblox-player:` URI back into a request
+    pub fn parse(uri: &str) -> Result<Self, String> {
+        let body = uri.trim()
+            .strip_prefix("roblox-player:")
+            .ok_or("Not a roblox-player: URI")?;
+        
+        let mut fields = std::collections::HashMap::new();
+        for part in body.split('+').skip(1) {
+            if let Some((key, value)) = part.split_once(':') {
+                fields.insert(key, value);
+            }
+        }
+        
+        let launch_mode = fields.get("launchmode").copied().unwrap_or("play");
+        let mode = match (launch_mode, fields.get("placelauncherurl")) {
+            ("app", _) => LaunchMode::App,
+            (_, Some(url)) => {
+                let url = urlencoding::decode(url).map_err(|e| format!("Bad placelauncherurl: {}", e))?;
+                parse_place_launcher_url(&url)?
+            }
+            (_, None) => return Err("URI has no placelauncherurl".to_string()),
+        };
+        
+        Ok(Self {
+            mode,
+            auth_ticket: fields.get("gameinfo").unwrap_or(&"").to_string(),
+            launch_time: fields.get("launchtime").and_then(|v| v.parse().ok()).unwrap_or_default(),
+            browser_tracker_id: fields.get("browsertrackerid").and_then(|v| v.parse().ok()).unwrap_or_default(),
+            locale: fields.get("robloxLocale").unwrap_or(&Self::DEFAULT_LOCALE).to_string(),
+            channel: fields.get("channel").unwrap_or(&"").to_string(),
+        })
+    }
+}
+
+fn parse_place_launcher_url(url: &str) -> Result<LaunchMode, String> {
+    let query = url.split_once('?').map(|(_, q)| q).unwrap_or("");
+    let params: std::collections::HashMap<String, String> = query.split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| {
+            let value = urlencoding::decode(value).map(|v| v.into_owned()).unwrap_or_else(|_| value.to_string());
+            (key.to_string(), value)
+        })
+        .collect();
+    
+    let text = |key: &str| params.get(key).cloned().ok_or_else(|| format!("Launcher URL is missing {}", key));
+    let id = |key: &str| text(key)?.parse::<u64>().map_err(|_| format!("Launcher URL has a bad {}", key));
+    
+    match text("request")?.as_str() {
+        "RequestGame" => Ok(LaunchMode::Place { place_id: id("placeId")? }),
+        "RequestGameJob" => Ok(LaunchMode::Job { place_id: id("placeId")?, job_id: text("gameId")? }),
+        "RequestPrivateGame" => Ok(LaunchMode::PrivateServer {
+            place_id: id("placeId")?,
+            access_code: text("accessCode")?,
+            link_code: params.get("linkCode").cloned(),
+        }),
+        "RequestFollowUser" => Ok(LaunchMode::FollowUser { user_id: id("userId")? }),
+        "RequestPlayTogetherGame" => Ok(LaunchMode::PlayTogether {
+            place_id: id("placeId")?,
+            conversation_id: text("conversationId")?,
+        }),
+        other => Err(format!("Unknown launch request type: {}", other)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn request(mode: LaunchMode) -> LaunchRequest {
+        LaunchRequest {
+            mode,
+            auth_ticket: "TICKET123".to_string(),
+            launch_time: 1_700_000_000_000,
+            browser_tracker_id: 123_456_789,
+            locale: "de_de".to_string(),
+            channel: "zbeta".to_string(),
+        }
+    }
+    
+    #[test]
+    fn test_round_trip_every_mode() {
+        let modes = [
+            LaunchMode::App,
+            LaunchMode::Place { place_id: 920587237 },
+            LaunchMode::Job { place_id: 920587237, job_id: "5f1c9e2a-0d5b-4c7e-9a43-1b2c3d4e5f60".to_string() },
+            LaunchMode::PrivateServer { place_id: 920587237, access_code: "a1b2c3d4-e5f6".to_string(), link_code: None },
+            LaunchMode::PrivateServer {
+                place_id: 920587237,
+                access_code: "a1b2c3d4-e5f6".to_string(),
+                link_code: Some("98765432109876543210".to_string()),
+            },
+            LaunchMode::FollowUser { user_id: 261 },
+            LaunchMode::PlayTogether { place_id: 920587237, conversation_id: "conv+1 &2".to_string() },
+        ];
+        
+        for mode in modes {
+            let original = request(mode);
+            let uri = original.to_uri();
+            assert_eq!(LaunchRequest::parse(&uri), Ok(original), "{}", uri);
+        }
+    }
+    
+    #[test]
+    fn test_uri_format() {
+        let uri = request(LaunchMode::Place { place_id: 1818 }).to_uri();
+        assert!(uri.starts_with("roblox-player:1+launchmode:play+gameinfo:TICKET123+launchtime:1700000000000+placelauncherurl:https%3A%2F%2F"));
+        assert!(uri.ends_with("+browsertrackerid:123456789+robloxLocale:de_de+gameLocale:de_de+channel:zbeta+LaunchExp:InApp"));
+        
+        let app = request(LaunchMode::App).to_uri();
+        assert!(app.starts_with("roblox-player:1+launchmode:app+"));
+        assert!(!app.contains("placelauncherurl"));
+        
+        assert!(LaunchRequest::parse("https://www.roblox.com/games/1818").is_err());
+        assert!(LaunchRequest::parse("roblox-player:1+launchmode:play+gameinfo:x").is_err());
+    }
+}
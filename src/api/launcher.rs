@@ -0,0 +1,134 @@
+use super::clients::ClientTracker;
+use crate::platform::Platform;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// How a `roblox-player:` URI reaches the client outside Windows
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+pub enum LauncherBackend {
+    /// Hand the URI to the desktop's registered `roblox-player` handler
+    #[default]
+    XdgOpen,
+    /// `flatpak run <app id> <uri>`, e.g. `org.vinegarhq.Sober`
+    Flatpak(String),
+    /// A command line with `{uri}` and `{account}` placeholders, run without a shell
+    Command(String),
+}
+
+impl LauncherBackend {
+    pub const DEFAULT_FLATPAK: &'static str = "org.vinegarhq.Sober";
+    pub const DEFAULT_COMMAND: &'static str = "wine \"$HOME/.wine/drive_c/Roblox/RobloxPlayerBeta.exe\" {uri}";
+    
+    pub fn label(&self) -> &'static str {
+        match self {
+            LauncherBackend::XdgOpen => "xdg-open",
+            LauncherBackend::Flatpak(_) => "Flatpak app",
+            LauncherBackend::Command(_) => "Custom command",
+        }
+    }
+}
+
+/// Per-launch settings passed down from the UI
+#[derive(Clone, Debug)]
+pub struct LaunchOptions {
+    /// Leave running clients alone instead of closing them first
+    pub multi_instance: bool,
+    /// Username of the launching account, for the `{account}` placeholder
+    pub account: String,
+    pub backend: LauncherBackend,
+    /// Client locale such as `en_us`, empty for the default
+    pub locale: String,
+    /// Release channel, empty for production
+    pub channel: String,
+    pub platform: Arc<dyn Platform>,
+    /// Where the launched client gets tracked
+    pub clients: Arc<ClientTracker>,
+}
+
+/// Split a command template into arguments and fill in the placeholders.
+/// Quotes group words; substitution happens per argument, so a URI never splits.
+/// `$HOME` and a leading `~` are expanded since there is no shell to do it.
+pub fn command_args(template: &str, uri: &str, account: &str) -> Result<Vec<String>, String> {
+    let home = dirs::home_dir().map(|h| h.to_string_lossy().into_owned()).unwrap_or_default();
+    
+    let args: Vec<String> = split_args(template)?
+        .into_iter()
+        .map(|arg| {
+            let arg = match arg.strip_prefix('~') {
+                Some(rest) if rest.is_empty() || rest.starts_with('/') => format!("{}{}", home, rest),
+                _ => arg,
+            };
+            arg.replace("$HOME", &home)
+                .replace("{uri}", uri)
+                .replace("{account}", account)
+        })
+        .collect();
+    
+    if args.is_empty() {
+        return Err("Launch command is empty".to_string());
+    }
+    if !template.contains("{uri}") {
+        return Err("Launch command must contain {uri}".to_string());
+    }
+    Ok(args)
+}
+
+fn split_args(input: &str) -> Result<Vec<String>, String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    let mut in_arg = false;
+    let mut quote: Option<char> = None;
+    
+    for c in input.chars() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), c) => current.push(c),
+            (None, '"' | '\'') => {
+                quote = Some(c);
+                in_arg = true;
+            }
+            (None, c) if c.is_whitespace() => {
+                if in_arg {
+                    args.push(std::mem::take(&mut current));
+                    in_arg = false;
+                }
+            }
+            (None, c) => {
+                current.push(c);
+                in_arg = true;
+            }
+        }
+    }
+    
+    if quote.is_some() {
+        return Err("Unclosed quote in launch command".to_string());
+    }
+    if in_arg {
+        args.push(current);
+    }
+    Ok(args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_command_args() {
+        let uri = "roblox-player:1+launchmode:play+gameinfo:abc";
+        
+        assert_eq!(
+            command_args("flatpak run org.vinegarhq.Sober {uri}", uri, "main").unwrap(),
+            vec!["flatpak", "run", "org.vinegarhq.Sober", uri]
+        );
+        assert_eq!(
+            command_args("env WINEPREFIX='/opt/prefix {account}' wine \"C:\\Roblox\\Player.exe\" {uri}", uri, "alt1").unwrap(),
+            vec!["env", "WINEPREFIX=/opt/prefix alt1", "wine", "C:\\Roblox\\Player.exe", uri]
+        );
+        assert_eq!(command_args("launch '' {uri}", uri, "a").unwrap(), vec!["launch", "", uri]);
+        
+        assert!(command_args("wine player.exe", uri, "a").is_err());
+        assert!(command_args("wine \"player.exe {uri}", uri, "a").is_err());
+        assert!(command_args("   ", uri, "a").is_err());
+    }
+}
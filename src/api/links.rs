@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+
+use super::{get_access_code_from_link, resolve_share_link, LaunchMode, LaunchRequest};
+
+/// Where a pasted or dropped Roblox link points
+#[derive(Clone, Debug, PartialEq)]
+pub enum LinkTarget {
+    /// Launchable as is
+    Launch(LaunchMode),
+    /// `privateServerLinkCode` link; an account has to turn the code into an access code
+    PrivateServer { place_id: Option<u64>, link_code: String },
+    /// `roblox.com/share?code=...&type=Server`, resolved to a private server by an account
+    Share { code: String },
+}
+
+impl LinkTarget {
+    pub fn place_id(&self) -> Option<u64> {
+        match self {
+            LinkTarget::Launch(mode) => mode.place_id(),
+            LinkTarget::PrivateServer { place_id, .. } => *place_id,
+            LinkTarget::Share { .. } => None,
+        }
+    }
+    
+    /// Short label for the UI
+    pub fn describe(&self) -> String {
+        match self {
+            LinkTarget::Launch(LaunchMode::App) => "Roblox app".to_string(),
+            LinkTarget::Launch(LaunchMode::Place { place_id }) => format!("Place {}", place_id),
+            LinkTarget::Launch(LaunchMode::Job { place_id, job_id }) => {
+                format!("Server {} in place {}", job_id.chars().take(8).collect::<String>(), place_id)
+            }
+            LinkTarget::Launch(LaunchMode::PrivateServer { place_id, .. }) => format!("Private server in place {}", place_id),
+            LinkTarget::Launch(LaunchMode::FollowUser { user_id }) => format!("Follow user {}", user_id),
+            LinkTarget::Launch(LaunchMode::PlayTogether { place_id, .. }) => format!("Party in place {}", place_id),
+            LinkTarget::PrivateServer { place_id: Some(place_id), .. } => format!("Private server in place {}", place_id),
+            LinkTarget::PrivateServer { place_id: None, .. } => "Private server".to_string(),
+            LinkTarget::Share { .. } => "Shared private server".to_string(),
+        }
+    }
+    
+    /// A link that resolves back to this private server, e.g. to save in a preset.
+    /// `None` for anything that isn't a private server.
+    pub fn private_server_link(&self) -> Option<String> {
+        match self {
+            LinkTarget::Launch(LaunchMode::PrivateServer { place_id, access_code, link_code }) => {
+                let mut link = format!("roblox://placeId={}&accessCode={}", place_id, urlencoding::encode(access_code));
+                if let Some(link_code) = link_code {
+                    link.push_str(&format!("&linkCode={}", urlencoding::encode(link_code)));
+                }
+                Some(link)
+            }
+            LinkTarget::PrivateServer { place_id, link_code } => Some(format!(
+                "https://www.roblox.com/games/{}?privateServerLinkCode={}",
+                place_id.map(|id| id.to_string()).unwrap_or_default(),
+                urlencoding::encode(link_code),
+            )),
+            LinkTarget::Share { code } => Some(format!("https://www.roblox.com/share?code={}&type=Server", urlencoding::encode(code))),
+            LinkTarget::Launch(_) => None,
+        }
+    }
+    
+    /// Use `place` for a private server link that doesn't name one
+    pub fn with_place(self, place: &str) -> Self {
+        match self {
+            LinkTarget::PrivateServer { place_id: None, link_code } => {
+                LinkTarget::PrivateServer { place_id: place.trim().parse().ok(), link_code }
+            }
+            target => target,
+        }
+    }
+    
+    /// Launch mode for this target. Share links and link codes are turned into an access
+    /// code with `cookie`, which blocks on the network.
+    pub fn launch_mode(&self, cookie: &str) -> Result<LaunchMode, String> {
+        let (place_id, link_code) = match self {
+            LinkTarget::Launch(mode) => return Ok(mode.clone()),
+            LinkTarget::PrivateServer { place_id: Some(place_id), link_code } => (*place_id, link_code.clone()),
+            LinkTarget::PrivateServer { place_id: None, .. } => {
+                return Err("Could not determine Place ID for the private server".to_string());
+            }
+            LinkTarget::Share { code } => resolve_share_link(cookie, code)?,
+        };
+        
+        let access_code = get_access_code_from_link(cookie, &place_id.to_string(), &link_code)?;
+        Ok(LaunchMode::PrivateServer { place_id, access_code, link_code: Some(link_code) })
+    }
+}
+
+/// Work out what a Roblox link points at. Understands game pages, share links, `roblox://`
+/// and `roblox-player:` URIs, `placeId`/`gameInstanceId` query pairs and profile URLs.
+/// Bare IDs and codes aren't links and give `None`.
+pub fn resolve_link(input: &str) -> Option<LinkTarget> {
+    let input = input.trim().trim_matches(|c| c == '"' || c == '\'' || c == '<' || c == '>');
+    // Internet shortcut files dropped from a browser
+    let input = input.strip_prefix("URL=").unwrap_or(input);
+    let lower = input.to_ascii_lowercase();
+    
+    if lower.starts_with("roblox-player:") {
+        return LaunchRequest::parse(input).ok().map(|request| LinkTarget::Launch(request.mode));
+    }
+    if !lower.starts_with("roblox://") && !lower.contains("roblox.com/") && !lower.contains("placeid=") {
+        return None;
+    }
+    
+    let params = query_params(input);
+    let param = |key: &str| params.get(key).map(String::as_str).filter(|v| !v.is_empty());
+    let number = |key: &str| param(key).and_then(|v| v.parse::<u64>().ok());
+    let place_id = number("placeid").or_else(|| path_id(&lower, "/games/"));
+    
+    if lower.contains("share") {
+        if let Some(code) = param("code") {
+            return match param("type") {
+                Some(kind) if !kind.eq_ignore_ascii_case("server") => None,
+                _ => Some(LinkTarget::Share { code: code.to_string() }),
+            };
+        }
+    }
+    
+    if let Some(link_code) = param("privateserverlinkcode").or(param("linkcode").filter(|_| param("accesscode").is_none())) {
+        return Some(LinkTarget::PrivateServer { place_id, link_code: link_code.to_string() });
+    }
+    
+    if let Some(place_id) = place_id {
+        if let Some(access_code) = param("accesscode") {
+            return Some(LinkTarget::Launch(LaunchMode::PrivateServer {
+                place_id,
+                access_code: access_code.to_string(),
+                link_code: param("linkcode").map(str::to_string),
+            }));
+        }
+        return Some(LinkTarget::Launch(match param("gameinstanceid").or(param("gameid")).or(param("jobid")) {
+            Some(job_id) => LaunchMode::Job { place_id, job_id: job_id.to_string() },
+            None => LaunchMode::Place { place_id },
+        }));
+    }
+    
+    number("userid")
+        .or_else(|| path_id(&lower, "/users/"))
+        .map(|user_id| LinkTarget::Launch(LaunchMode::FollowUser { user_id }))
+}
+
+/// The first link in `text`, e.g. the contents of a dropped file
+pub fn find_link(text: &str) -> Option<LinkTarget> {
+    text.split_whitespace().find_map(resolve_link)
+}
+
+/// Query pairs with lowercased keys. `roblox://` URIs may carry them without a `?`.
+fn query_params(input: &str) -> HashMap<String, String> {
+    let input = input.split('#').next().unwrap_or(input);
+    let query = match input.split_once('?') {
+        Some((_, query)) => query,
+        None => input.split_once("://").map_or("", |(_, rest)| rest),
+    };
+    
+    query.split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| {
+            let value = urlencoding::decode(value).map(|v| v.into_owned()).unwrap_or_else(|_| value.to_string());
+            (key.to_ascii_lowercase(), value)
+        })
+        .collect()
+}
+
+/// Number right after `segment` in a URL path, as in `/games/1818/name`
+fn path_id(lower: &str, segment: &str) -> Option<u64> {
+    let rest = &lower[lower.find(segment)? + segment.len()..];
+    let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+    digits.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn launch(mode: LaunchMode) -> Option<LinkTarget> {
+        Some(LinkTarget::Launch(mode))
+    }
+    
+    #[test]
+    fn test_resolve_links() {
+        let job = LaunchMode::Job { place_id: 920587237, job_id: "5f1c9e2a-0d5b".to_string() };
+        
+        assert_eq!(resolve_link("https://www.roblox.com/games/920587237/Adopt-Me"), launch(LaunchMode::Place { place_id: 920587237 }));
+        assert_eq!(resolve_link("roblox.com/games/920587237?gameInstanceId=5f1c9e2a-0d5b"), launch(job.clone()));
+        assert_eq!(resolve_link("https://www.roblox.com/games/start?placeId=920587237&gameInstanceId=5f1c9e2a-0d5b"), launch(job.clone()));
+        assert_eq!(resolve_link("roblox://placeId=920587237&gameInstanceId=5f1c9e2a-0d5b"), launch(job.clone()));
+        assert_eq!(resolve_link("roblox://experiences/start?placeId=920587237"), launch(LaunchMode::Place { place_id: 920587237 }));
+        assert_eq!(resolve_link("https://www.roblox.com/users/261/profile"), launch(LaunchMode::FollowUser { user_id: 261 }));
+        assert_eq!(resolve_link("URL=https://www.roblox.com/users/261/profile"), launch(LaunchMode::FollowUser { user_id: 261 }));
+        
+        assert_eq!(
+            resolve_link("https://www.roblox.com/share?code=8f2e4c1b9a&type=Server"),
+            Some(LinkTarget::Share { code: "8f2e4c1b9a".to_string() })
+        );
+        assert_eq!(resolve_link("https://www.roblox.com/share?code=8f2e4c1b9a&type=ExperienceDetails"), None);
+        assert_eq!(
+            resolve_link("https://www.roblox.com/games/920587237/Adopt-Me?privateServerLinkCode=12345678901234567890"),
+            Some(LinkTarget::PrivateServer { place_id: Some(920587237), link_code: "12345678901234567890".to_string() })
+        );
+        
+        let uri = LaunchRequest::new(job.clone()).to_uri();
+        assert_eq!(resolve_link(&uri), launch(job.clone()));
+        
+        // Private servers can be written back out as links
+        let servers = [
+            LinkTarget::Launch(LaunchMode::PrivateServer { place_id: 1818, access_code: "a1b2-c3".to_string(), link_code: Some("555".to_string()) }),
+            LinkTarget::PrivateServer { place_id: Some(1818), link_code: "12345678901234567890".to_string() },
+            LinkTarget::PrivateServer { place_id: None, link_code: "12345678901234567890".to_string() },
+            LinkTarget::Share { code: "8f2e4c1b9a".to_string() },
+        ];
+        for server in servers {
+            assert_eq!(resolve_link(&server.private_server_link().unwrap()), Some(server));
+        }
+        assert_eq!(LinkTarget::Launch(job).private_server_link(), None);
+        
+        assert_eq!(resolve_link("920587237"), None);
+        assert_eq!(resolve_link("VIP:some-access-code"), None);
+        assert_eq!(resolve_link("https://example.com/games/1818"), None);
+        assert_eq!(find_link("[InternetShortcut]\r\nURL=https://www.roblox.com/games/1818\r\n"), launch(LaunchMode::Place { place_id: 1818 }));
+    }
+}
@@ -1,8 +1,21 @@
 mod api;
+pub mod batch;
+pub mod clients;
+pub mod launcher;
+pub mod launch_request;
+pub mod launch_queue;
+pub mod links;
 pub mod server_browser;
 pub mod private_server;
+pub mod privacy;
 
-pub use api::RobloxApi;
+pub use api::{AccountInfo, BlockedUser, FriendInfo, FriendRequest, RobloxApi};
+pub use batch::BatchTask;
+pub use launcher::{LaunchOptions, LauncherBackend};
+pub use launch_request::{LaunchMode, LaunchRequest};
+pub use launch_queue::{LaunchQueue, LaunchState, QueueJob};
+pub use links::{find_link, resolve_link, LinkTarget};
 pub use server_browser::{fetch_servers, get_random_server, ServerBrowser, ServerData};
-pub use private_server::{PrivateServerLink, fetch_vip_servers, get_access_code_from_link};
+pub use private_server::{PrivateServerLink, fetch_vip_servers, get_access_code_from_link, resolve_share_link};
+pub use privacy::PRIVACY_SETTINGS;
 
@@ -0,0 +1,93 @@
+/// One editable privacy option: where it lives, the JSON field it's read and written
+/// through, and the values Roblox accepts with their labels
+pub struct PrivacySetting {
+    pub label: &'static str,
+    pub url: &'static str,
+    pub field: &'static str,
+    pub options: &'static [(&'static str, &'static str)],
+}
+
+impl PrivacySetting {
+    pub fn option_label(&self, value: &str) -> &'static str {
+        self.options.iter()
+            .find(|(v, _)| *v == value)
+            .map(|(_, label)| *label)
+            .unwrap_or("Unknown")
+    }
+}
+
+/// Every setting shown in the Privacy editor. Each is read with a GET on `url`
+/// and changed with a POST of `{ field: value }` to the same URL.
+pub const PRIVACY_SETTINGS: &[PrivacySetting] = &[
+    PrivacySetting {
+        label: "Who can join me",
+        url: "https://apis.roblox.com/user-settings-api/v1/user-settings",
+        field: "whoCanJoinMeInExperiences",
+        options: &[
+            ("AllUsers", "Everyone"),
+            ("FriendsFollowingAndFollowers", "Friends, following & followers"),
+            ("FriendsAndFollowing", "Friends & following"),
+            ("Friends", "Friends"),
+            ("NoOne", "No one"),
+        ],
+    },
+    PrivacySetting {
+        label: "Who can message me",
+        url: "https://accountsettings.roblox.com/v1/private-message-privacy",
+        field: "privateMessagePrivacy",
+        options: &[
+            ("All", "Everyone"),
+            ("Followers", "Followers"),
+            ("Following", "Following"),
+            ("Friends", "Friends"),
+            ("NoOne", "No one"),
+        ],
+    },
+    PrivacySetting {
+        label: "Who can chat with me in app",
+        url: "https://accountsettings.roblox.com/v1/app-chat-privacy",
+        field: "appChatPrivacy",
+        options: &[
+            ("All", "Everyone"),
+            ("Friends", "Friends"),
+            ("NoOne", "No one"),
+        ],
+    },
+    PrivacySetting {
+        label: "Who can chat with me in experiences",
+        url: "https://accountsettings.roblox.com/v1/game-chat-privacy",
+        field: "gameChatPrivacy",
+        options: &[
+            ("All", "Everyone"),
+            ("Friends", "Friends"),
+            ("NoOne", "No one"),
+        ],
+    },
+    PrivacySetting {
+        label: "Who can see my inventory",
+        url: "https://accountsettings.roblox.com/v1/inventory-privacy",
+        field: "inventoryPrivacy",
+        options: &[
+            ("AllUsers", "Everyone"),
+            ("AllAuthenticatedUsers", "Signed-in users"),
+            ("FriendsFollowingAndFollowers", "Friends, following & followers"),
+            ("FriendsAndFollowing", "Friends & following"),
+            ("Friends", "Friends"),
+            ("NoOne", "No one"),
+        ],
+    },
+    PrivacySetting {
+        label: "Who can trade with me",
+        url: "https://accountsettings.roblox.com/v1/trade-privacy",
+        field: "tradePrivacy",
+        options: &[
+            ("All", "Everyone"),
+            ("Followers", "Followers"),
+            ("Following", "Following"),
+            ("Friends", "Friends"),
+            ("TopFriends", "Top friends"),
+            ("NoOne", "No one"),
+            ("Disabled", "Disabled"),
+        ],
+    },
+];
@@ -208,6 +208,61 @@ pub fn get_access_code_from_link(cookie: &str, place_id: &str, link_code: &str)
     })
 }
 
+/// Resolve a `roblox.com/share` server link into its place and private server link code
+pub fn resolve_share_link(cookie: &str, code: &str) -> Result<(u64, String), String> {
+    let cookie = cookie.to_string();
+    let body = serde_json::json!({ "linkId": code, "linkType": "Server" });
+    
+    run_async(async move {
+        let client = reqwest::Client::new();
+        let url = "https://apis.roblox.com/sharelinks/v1/resolve-link";
+        
+        // The first request only hands out a CSRF token
+        let csrf_resp = client
+            .post(url)
+            .header("Cookie", format!(".ROBLOSECURITY={}", cookie))
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| format!("CSRF request failed: {}", e))?;
+        
+        let csrf_token = csrf_resp
+            .headers()
+            .get("x-csrf-token")
+            .and_then(|h| h.to_str().ok())
+            .map(|s| s.to_string())
+            .unwrap_or_default();
+        
+        let resp = client
+            .post(url)
+            .header("Cookie", format!(".ROBLOSECURITY={}", cookie))
+            .header("X-CSRF-TOKEN", &csrf_token)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| format!("Request failed: {}", e))?;
+        
+        if !resp.status().is_success() {
+            return Err(format!("Failed to resolve share link: HTTP {}", resp.status()));
+        }
+        
+        let data: serde_json::Value = resp.json().await
+            .map_err(|e| format!("Failed to parse share link response: {}", e))?;
+        let invite = data.get("privateServerInviteData")
+            .ok_or("Share link isn't a private server invite")?;
+        
+        if invite.get("status").and_then(|v| v.as_str()).is_some_and(|s| s != "Valid") {
+            return Err("Share link has expired or is no longer valid".to_string());
+        }
+        
+        let place_id = invite.get("placeId").and_then(|v| v.as_u64())
+            .ok_or("Share link has no place")?;
+        let link_code = invite.get("linkCode").and_then(|v| v.as_str())
+            .ok_or("Share link has no link code")?;
+        Ok((place_id, link_code.to_string()))
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,54 +1,177 @@
+use std::collections::HashSet;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{channel, Receiver};
 
 #[cfg(windows)]
 use winreg::enums::*;
 #[cfg(windows)]
 use winreg::RegKey;
 
+/// Chromium-based browsers on Windows: display name, user data directory, and
+/// whether that directory lives under `%APPDATA%` rather than `%LOCALAPPDATA%`
+#[cfg(windows)]
+const WINDOWS_CHROMIUM_BROWSERS: &[(&str, &str, bool)] = &[
+    ("Google Chrome", "Google/Chrome/User Data", false),
+    ("Microsoft Edge", "Microsoft/Edge/User Data", false),
+    ("Brave", "BraveSoftware/Brave-Browser/User Data", false),
+    ("Vivaldi", "Vivaldi/User Data", false),
+    ("Opera", "Opera Software/Opera Stable", true),
+    ("Opera GX", "Opera Software/Opera GX Stable", true),
+];
+
+/// Chromium-based browsers on Linux: display name, directory under `~/.config`,
+/// and the `application` attribute of their Secret Service entry
+#[cfg(target_os = "linux")]
+const LINUX_CHROMIUM_BROWSERS: &[(&str, &str, &str)] = &[
+    ("Google Chrome", "google-chrome", "chrome"),
+    ("Chromium", "chromium", "chromium"),
+    ("Brave", "BraveSoftware/Brave-Browser", "brave"),
+    ("Microsoft Edge", "microsoft-edge", "microsoft-edge"),
+    ("Opera", "opera", "opera"),
+    ("Vivaldi", "vivaldi", "vivaldi"),
+];
+
+/// What Roblox said about a found session
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum CookieCheck {
+    /// Not checked yet
+    #[default]
+    Pending,
+    Valid,
+    /// Past its expiry date or rejected by Roblox
+    Expired,
+    /// The check couldn't reach Roblox
+    Unknown,
+}
+
+/// Progress from a background cookie search
+pub enum ScanEvent {
+    /// Every distinct session on disk, before validation
+    Found(Vec<FoundCookie>),
+    /// The session at this index after checking it
+    Checked(usize, FoundCookie),
+}
+
 #[derive(Clone)]
 pub struct FoundCookie {
     pub cookie: String,
-    pub source: String,
+    /// Browser (or other store) the session was found in
+    pub browser: String,
+    /// Browser profile directory, empty when the store has no profiles
+    pub profile: String,
+    /// Expiry as a unix timestamp, `None` for session cookies
+    pub expires: Option<i64>,
+    pub check: CookieCheck,
+    /// Set once the cookie has been checked against the API
+    pub user_id: Option<u64>,
+    pub username: Option<String>,
+}
+
+impl FoundCookie {
+    fn new(cookie: String, browser: &str, profile: &str, expires: Option<i64>) -> Self {
+        Self {
+            cookie,
+            browser: browser.to_string(),
+            profile: profile.to_string(),
+            expires,
+            check: CookieCheck::Pending,
+            user_id: None,
+            username: None,
+        }
+    }
+    
+    /// "Browser · Profile" for display
+    pub fn source(&self) -> String {
+        if self.profile.is_empty() {
+            self.browser.clone()
+        } else {
+            format!("{} · {}", self.browser, self.profile)
+        }
+    }
+    
+    pub fn is_expired(&self) -> bool {
+        self.expires.is_some_and(|e| e < chrono::Utc::now().timestamp())
+    }
+    
+    fn validate(&mut self) {
+        if self.is_expired() {
+            self.check = CookieCheck::Expired;
+            return;
+        }
+        
+        self.check = match crate::api::RobloxApi::check_cookie(&self.cookie) {
+            Ok(Some((user_id, display_name))) => {
+                self.user_id = Some(user_id);
+                self.username = Some(
+                    crate::api::RobloxApi::get_username_by_id(user_id).unwrap_or(display_name)
+                );
+                CookieCheck::Valid
+            }
+            Ok(None) => CookieCheck::Expired,
+            Err(_) => CookieCheck::Unknown,
+        };
+    }
+    
+    /// Short state for the found cookie lists
+    pub fn status_label(&self) -> &str {
+        match (&self.username, self.check) {
+            (Some(name), _) => name,
+            (None, CookieCheck::Pending) => "Checking...",
+            (None, CookieCheck::Expired) => "Expired",
+            (None, _) => "Unknown",
+        }
+    }
 }
 
 pub struct CookieFinder;
 
 impl CookieFinder {
+    /// Find and validate sessions on a background thread. Sessions are sent as soon as
+    /// they're read from disk, then again one by one as each is checked.
+    pub fn spawn_scan() -> Receiver<ScanEvent> {
+        let (sender, receiver) = channel();
+        std::thread::spawn(move || {
+            let cookies = Self::find_all_cookies();
+            if sender.send(ScanEvent::Found(cookies.clone())).is_err() {
+                return;
+            }
+            for (idx, mut cookie) in cookies.into_iter().enumerate() {
+                cookie.validate();
+                if sender.send(ScanEvent::Checked(idx, cookie)).is_err() {
+                    return;
+                }
+            }
+        });
+        receiver
+    }
+    
+    /// Every distinct session in the supported browser profiles, not yet validated
     pub fn find_all_cookies() -> Vec<FoundCookie> {
         let mut cookies = Vec::new();
         
         #[cfg(windows)]
         {
             if let Some(cookie) = Self::find_in_registry() {
-                cookies.push(FoundCookie {
-                    cookie,
-                    source: "Windows Registry".to_string(),
-                });
-            }
-            
-            if let Some(cookie) = Self::find_in_chrome() {
-                cookies.push(FoundCookie {
-                    cookie,
-                    source: "Google Chrome".to_string(),
-                });
+                cookies.push(FoundCookie::new(cookie, "Windows Registry", "", None));
             }
             
-            if let Some(cookie) = Self::find_in_edge() {
-                cookies.push(FoundCookie {
-                    cookie,
-                    source: "Microsoft Edge".to_string(),
-                });
-            }
+            cookies.extend(Self::find_windows_chromium_cookies());
             
-            if let Some(cookie) = Self::find_in_firefox() {
-                cookies.push(FoundCookie {
-                    cookie,
-                    source: "Mozilla Firefox".to_string(),
-                });
+            if let Ok(app_data) = std::env::var("APPDATA") {
+                let profiles = PathBuf::from(&app_data).join("Mozilla/Firefox/Profiles");
+                cookies.extend(scan_firefox_profiles(&profiles));
             }
         }
         
+        #[cfg(target_os = "linux")]
+        {
+            cookies.extend(Self::find_linux_cookies());
+        }
+        
+        // The same session often shows up in several places, e.g. the registry and a browser
+        let mut seen = HashSet::new();
+        cookies.retain(|c| seen.insert(c.cookie.clone()));
         cookies
     }
     
@@ -76,53 +199,34 @@ impl CookieFinder {
     }
     
     #[cfg(windows)]
-    fn find_in_chrome() -> Option<String> {
-        let local_app_data = std::env::var("LOCALAPPDATA").ok()?;
-        let cookie_path = PathBuf::from(&local_app_data)
-            .join("Google/Chrome/User Data/Default/Network/Cookies");
-        
-        Self::extract_chromium_cookie(&cookie_path, &local_app_data, "Google/Chrome/User Data/Local State")
-    }
-    
-    #[cfg(windows)]
-    fn find_in_edge() -> Option<String> {
-        let local_app_data = std::env::var("LOCALAPPDATA").ok()?;
-        let cookie_path = PathBuf::from(&local_app_data)
-            .join("Microsoft/Edge/User Data/Default/Network/Cookies");
+    fn find_windows_chromium_cookies() -> Vec<FoundCookie> {
+        let mut cookies = Vec::new();
         
-        Self::extract_chromium_cookie(&cookie_path, &local_app_data, "Microsoft/Edge/User Data/Local State")
-    }
-    
-    #[cfg(windows)]
-    fn extract_chromium_cookie(cookie_db_path: &PathBuf, local_app_data: &str, local_state_rel: &str) -> Option<String> {
-        use rusqlite::Connection;
+        let local_app_data = std::env::var("LOCALAPPDATA").ok();
+        let app_data = std::env::var("APPDATA").ok();
         
-        if !cookie_db_path.exists() {
-            return None;
+        for (name, rel, roaming) in WINDOWS_CHROMIUM_BROWSERS {
+            let base = if *roaming { &app_data } else { &local_app_data };
+            let Some(base) = base else { continue };
+            
+            let user_data = PathBuf::from(base).join(rel);
+            if !user_data.exists() {
+                continue;
+            }
+            
+            // One key per user data directory, shared by all of its profiles
+            let Some(key) = Self::get_chromium_key(&user_data.join("Local State")) else { continue };
+            
+            cookies.extend(scan_chromium_browser(name, &user_data, |encrypted, has_host_hash| {
+                Self::decrypt_chromium_cookie(encrypted, &key, has_host_hash)
+            }));
         }
         
-        let temp_path = std::env::temp_dir().join("nexus_cookies_temp.db");
-        fs::copy(cookie_db_path, &temp_path).ok()?;
-        
-        let local_state_path = PathBuf::from(local_app_data).join(local_state_rel);
-        let key = Self::get_chromium_key(&local_state_path)?;
-        
-        let conn = Connection::open(&temp_path).ok()?;
-        
-        let mut stmt = conn.prepare(
-            "SELECT encrypted_value FROM cookies WHERE host_key LIKE '%roblox.com' AND name = '.ROBLOSECURITY' ORDER BY creation_utc DESC LIMIT 1"
-        ).ok()?;
-        
-        let encrypted: Vec<u8> = stmt.query_row([], |row| row.get(0)).ok()?;
-        
-
-        fs::remove_file(&temp_path).ok();
-        
-        Self::decrypt_chromium_cookie(&encrypted, &key)
+        cookies
     }
     
     #[cfg(windows)]
-    fn get_chromium_key(local_state_path: &PathBuf) -> Option<Vec<u8>> {
+    fn get_chromium_key(local_state_path: &Path) -> Option<Vec<u8>> {
         use base64::Engine;
         use windows::Win32::Security::Cryptography::{CryptUnprotectData, CRYPT_INTEGER_BLOB};
         
@@ -172,7 +276,7 @@ impl CookieFinder {
     }
     
     #[cfg(windows)]
-    fn decrypt_chromium_cookie(encrypted: &[u8], key: &[u8]) -> Option<String> {
+    fn decrypt_chromium_cookie(encrypted: &[u8], key: &[u8], has_host_hash: bool) -> Option<String> {
         use aes_gcm::{Aes256Gcm, KeyInit, aead::Aead};
         use aes_gcm::aead::generic_array::GenericArray;
         
@@ -193,47 +297,478 @@ impl CookieFinder {
         let nonce = GenericArray::from_slice(nonce);
         
         let decrypted = cipher.decrypt(nonce, ciphertext).ok()?;
-        String::from_utf8(decrypted).ok()
+        let plaintext = if has_host_hash {
+            decrypted.get(32..)?
+        } else {
+            &decrypted[..]
+        };
+        String::from_utf8(plaintext.to_vec()).ok()
     }
     
-    #[cfg(windows)]
-    fn find_in_firefox() -> Option<String> {
-        use rusqlite::Connection;
+    #[cfg(target_os = "linux")]
+    fn find_linux_cookies() -> Vec<FoundCookie> {
+        let mut cookies = Vec::new();
         
-        let app_data = std::env::var("APPDATA").ok()?;
-        let profiles_path = PathBuf::from(&app_data).join("Mozilla/Firefox/Profiles");
+        let home = match dirs::home_dir() {
+            Some(h) => h,
+            None => return cookies,
+        };
         
-        if !profiles_path.exists() {
-            return None;
-        }
+        let v10_key = linux_chromium_key(b"peanuts");
         
-        for entry in fs::read_dir(&profiles_path).ok()? {
-            let entry = entry.ok()?;
-            let path = entry.path();
+        for (name, config_rel, application) in LINUX_CHROMIUM_BROWSERS {
+            let user_data = home.join(".config").join(config_rel);
+            if !user_data.exists() || !is_own_file(&user_data, &home) {
+                continue;
+            }
+            
+            // v11 values need the key from the keyring; only ask for it once the browser exists
+            let v11_key = secret_service_password(application)
+                .map(|password| linux_chromium_key(password.as_bytes()));
             
-            if path.is_dir() {
-                let cookies_path = path.join("cookies.sqlite");
-                if cookies_path.exists() {
-                    let temp_path = std::env::temp_dir().join("nexus_ff_cookies_temp.db");
-                    if fs::copy(&cookies_path, &temp_path).is_ok() {
-                        if let Ok(conn) = Connection::open(&temp_path) {
-                            let result: Result<String, _> = conn.query_row(
-                                "SELECT value FROM moz_cookies WHERE host LIKE '%roblox.com' AND name = '.ROBLOSECURITY' ORDER BY lastAccessed DESC LIMIT 1",
-                                [],
-                                |row| row.get(0),
-                            );
-                            
-                            fs::remove_file(&temp_path).ok();
-                            
-                            if let Ok(cookie) = result {
-                                return Some(cookie);
-                            }
-                        }
-                    }
+            cookies.extend(scan_chromium_browser(name, &user_data, |encrypted, has_host_hash| {
+                decrypt_linux_chromium_cookie(encrypted, &v10_key, v11_key.as_ref(), has_host_hash)
+            }));
+        }
+        
+        let firefox_profiles = home.join(".mozilla/firefox");
+        if is_own_file(&firefox_profiles, &home) {
+            cookies.extend(scan_firefox_profiles(&firefox_profiles));
+        }
+        
+        cookies
+    }
+}
+
+/// Cookie databases of the profiles inside a Chromium user data directory: `Default` and
+/// `Profile N`. Opera keeps its single profile directly in the user data directory.
+fn chromium_profiles(user_data: &Path) -> Vec<(String, PathBuf)> {
+    let mut profiles = Vec::new();
+    
+    if let Ok(entries) = fs::read_dir(user_data) {
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if (name == "Default" || name.starts_with("Profile ")) && entry.path().is_dir() {
+                if let Some(db) = chromium_cookie_db(&entry.path()) {
+                    profiles.push((name, db));
                 }
             }
         }
+    }
+    
+    if !profiles.iter().any(|(name, _)| name == "Default") {
+        if let Some(db) = chromium_cookie_db(user_data) {
+            profiles.push(("Default".to_string(), db));
+        }
+    }
+    
+    profiles.sort_by(|a, b| a.0.cmp(&b.0));
+    // Symlinked profiles can lead to the same database
+    let mut seen = HashSet::new();
+    profiles.retain(|(_, db)| seen.insert(db.canonicalize().unwrap_or_else(|_| db.clone())));
+    profiles
+}
+
+fn chromium_cookie_db(profile: &Path) -> Option<PathBuf> {
+    ["Network/Cookies", "Cookies"].iter()
+        .map(|rel| profile.join(rel))
+        .find(|p| p.is_file())
+}
+
+/// Collect sessions from every profile of one Chromium browser, decrypting with `decrypt`
+fn scan_chromium_browser(
+    browser: &str,
+    user_data: &Path,
+    decrypt: impl Fn(&[u8], bool) -> Option<String>,
+) -> Vec<FoundCookie> {
+    let mut cookies = Vec::new();
+    
+    for (profile, db) in chromium_profiles(user_data) {
+        if !may_read(&db, user_data) {
+            continue;
+        }
         
+        for (cookie, expires) in read_chromium_cookies(&db, &decrypt) {
+            cookies.push(FoundCookie::new(cookie, browser, &profile, expires));
+        }
+    }
+    
+    cookies
+}
+
+/// Collect sessions from every profile under a Firefox `Profiles` directory
+fn scan_firefox_profiles(profiles_dir: &Path) -> Vec<FoundCookie> {
+    let mut cookies = Vec::new();
+    
+    let Ok(entries) = fs::read_dir(profiles_dir) else {
+        return cookies;
+    };
+    
+    let mut profiles: Vec<PathBuf> = entries.flatten()
+        .map(|e| e.path())
+        .filter(|p| p.join("cookies.sqlite").is_file() && may_read(&p.join("cookies.sqlite"), profiles_dir))
+        .collect();
+    profiles.sort();
+    
+    for dir in profiles {
+        let profile = dir.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+        
+        for (cookie, expires) in read_firefox_cookies(&dir.join("cookies.sqlite")) {
+            cookies.push(FoundCookie::new(cookie, "Mozilla Firefox", &profile, expires));
+        }
+    }
+    
+    cookies
+}
+
+/// Whether a database found under `root` may be opened. On Linux it has to be the user's own
+/// file inside `root`; elsewhere browser data lives in per-user directories already.
+fn may_read(db: &Path, root: &Path) -> bool {
+    #[cfg(target_os = "linux")]
+    return is_own_file(db, root);
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = (db, root);
+        true
+    }
+}
+
+/// Copy a browser database out of the way so a running browser's lock doesn't get in the way
+fn copy_to_temp(path: &Path, tag: &str) -> Option<PathBuf> {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    let temp_path = std::env::temp_dir()
+        .join(format!("nexus_{}_{}_{}.db", tag, std::process::id(), nanos));
+    fs::copy(path, &temp_path).ok()?;
+    Some(temp_path)
+}
+
+/// Read every `.ROBLOSECURITY` row, newest first, with its expiry as a unix timestamp.
+/// Databases from schema version 24 onwards prefix the plaintext with a SHA-256 of the host,
+/// which `decrypt` is told to strip.
+fn read_chromium_cookies(
+    cookie_db_path: &Path,
+    decrypt: impl Fn(&[u8], bool) -> Option<String>,
+) -> Vec<(String, Option<i64>)> {
+    use rusqlite::Connection;
+    
+    // Chromium stores expiry in microseconds since 1601-01-01
+    const WINDOWS_EPOCH_OFFSET: i64 = 11_644_473_600;
+    
+    let Some(temp_path) = copy_to_temp(cookie_db_path, "chromium_cookies") else {
+        return Vec::new();
+    };
+    
+    let result = (|| {
+        let conn = Connection::open(&temp_path).ok()?;
+        
+        let version: i64 = conn.query_row(
+            "SELECT value FROM meta WHERE key = 'version'",
+            [],
+            |row| row.get::<_, String>(0),
+        ).ok().and_then(|v| v.parse().ok()).unwrap_or(0);
+        
+        let mut stmt = conn.prepare(
+            "SELECT value, encrypted_value, expires_utc FROM cookies WHERE host_key LIKE '%roblox.com' AND name = '.ROBLOSECURITY' ORDER BY creation_utc DESC"
+        ).ok()?;
+        
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, Vec<u8>>(1)?, row.get::<_, i64>(2)?))
+        }).ok()?;
+        
+        let mut cookies = Vec::new();
+        for (value, encrypted, expires_utc) in rows.flatten() {
+            let cookie = if value.is_empty() {
+                decrypt(&encrypted, version >= 24)
+            } else {
+                Some(value)
+            };
+            
+            if let Some(cookie) = cookie {
+                let expires = (expires_utc > 0).then(|| expires_utc / 1_000_000 - WINDOWS_EPOCH_OFFSET);
+                cookies.push((cookie, expires));
+            }
+        }
+        
+        Some(cookies)
+    })();
+    
+    fs::remove_file(&temp_path).ok();
+    result.unwrap_or_default()
+}
+
+fn read_firefox_cookies(cookies_path: &Path) -> Vec<(String, Option<i64>)> {
+    use rusqlite::Connection;
+    
+    let Some(temp_path) = copy_to_temp(cookies_path, "ff_cookies") else {
+        return Vec::new();
+    };
+    
+    let result = (|| {
+        let conn = Connection::open(&temp_path).ok()?;
+        
+        let mut stmt = conn.prepare(
+            "SELECT value, expiry FROM moz_cookies WHERE host LIKE '%roblox.com' AND name = '.ROBLOSECURITY' ORDER BY lastAccessed DESC"
+        ).ok()?;
+        
+        let rows = stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+        }).ok()?;
+        
+        // Newer Firefox versions store the expiry in milliseconds
+        let cookies = rows.flatten()
+            .map(|(value, expiry)| {
+                let expires = if expiry > 100_000_000_000 { expiry / 1000 } else { expiry };
+                (value, (expires > 0).then_some(expires))
+            })
+            .collect();
+        
+        Some(cookies)
+    })();
+    
+    fs::remove_file(&temp_path).ok();
+    result.unwrap_or_default()
+}
+
+/// True if `path` resolves inside `root` and belongs to the current user,
+/// so symlinks into other users' profiles are never read
+#[cfg(target_os = "linux")]
+fn is_own_file(path: &Path, root: &Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+    
+    let (Ok(path), Ok(root)) = (path.canonicalize(), root.canonicalize()) else {
+        return false;
+    };
+    
+    if !path.starts_with(&root) {
+        return false;
+    }
+    
+    let uid = unsafe { libc::getuid() };
+    fs::metadata(&path).map(|m| m.uid() == uid).unwrap_or(false)
+}
+
+/// Chromium's Linux cookie key: PBKDF2-SHA1 over the password with a fixed salt and one iteration
+#[cfg(target_os = "linux")]
+fn linux_chromium_key(password: &[u8]) -> [u8; 16] {
+    let mut key = [0u8; 16];
+    pbkdf2::pbkdf2_hmac::<sha1::Sha1>(password, b"saltysalt", 1, &mut key);
+    key
+}
+
+/// Look up the browser's "Safe Storage" password through `secret-tool`
+#[cfg(target_os = "linux")]
+fn secret_service_password(application: &str) -> Option<String> {
+    let output = std::process::Command::new("secret-tool")
+        .args(["lookup", "application", application])
+        .output()
+        .ok()?;
+    
+    if !output.status.success() {
+        return None;
+    }
+    
+    let password = String::from_utf8(output.stdout).ok()?;
+    let password = password.trim_end_matches('\n');
+    if password.is_empty() {
         None
+    } else {
+        Some(password.to_string())
+    }
+}
+
+/// Decrypt a Linux `v10`/`v11` cookie value (AES-128-CBC with a fixed IV)
+#[cfg(target_os = "linux")]
+fn decrypt_linux_chromium_cookie(
+    encrypted: &[u8],
+    v10_key: &[u8; 16],
+    v11_key: Option<&[u8; 16]>,
+    has_host_hash: bool,
+) -> Option<String> {
+    use cbc::cipher::{block_padding::Pkcs7, BlockDecryptMut, KeyIvInit};
+    
+    if encrypted.len() < 3 {
+        return None;
+    }
+    
+    let key = match &encrypted[0..3] {
+        b"v10" => v10_key,
+        b"v11" => v11_key?,
+        _ => return None,
+    };
+    
+    let iv = [b' '; 16];
+    let decrypted = cbc::Decryptor::<aes::Aes128>::new(key.into(), &iv.into())
+        .decrypt_padded_vec_mut::<Pkcs7>(&encrypted[3..])
+        .ok()?;
+    
+    let plaintext = if has_host_hash {
+        decrypted.get(32..)?
+    } else {
+        &decrypted[..]
+    };
+    
+    String::from_utf8(plaintext.to_vec()).ok()
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+    use rusqlite::Connection;
+    
+    const COOKIE: &str = "_|WARNING:-DO-NOT-SHARE-THIS.--Sharing-this-will-allow-someone-to-log-in-as-you-and-to-steal-your-ROBUX-and-items.|_TESTCOOKIE";
+    
+    fn fixture_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("nexus_cookie_test_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+    
+    fn encrypt(prefix: &[u8], key: &[u8; 16], plaintext: &[u8]) -> Vec<u8> {
+        use cbc::cipher::{block_padding::Pkcs7, BlockEncryptMut, KeyIvInit};
+        
+        let iv = [b' '; 16];
+        let mut out = prefix.to_vec();
+        out.extend(cbc::Encryptor::<aes::Aes128>::new(key.into(), &iv.into())
+            .encrypt_padded_vec_mut::<Pkcs7>(plaintext));
+        out
+    }
+    
+    fn chromium_db(path: &Path, version: &str, encrypted: &[u8]) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let conn = Connection::open(path).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE meta (key TEXT, value TEXT);
+             CREATE TABLE cookies (host_key TEXT, name TEXT, value TEXT, encrypted_value BLOB, creation_utc INTEGER, expires_utc INTEGER);"
+        ).unwrap();
+        conn.execute("INSERT INTO meta VALUES ('version', ?1)", [version]).unwrap();
+        // 2030-01-01 in Chromium's 1601-based microseconds
+        conn.execute(
+            "INSERT INTO cookies VALUES ('.roblox.com', '.ROBLOSECURITY', '', ?1, 1, 13537929600000000)",
+            [encrypted],
+        ).unwrap();
+        conn.execute(
+            "INSERT INTO cookies VALUES ('.example.com', '.ROBLOSECURITY', 'not-roblox', x'', 2, 0)",
+            [],
+        ).unwrap();
+    }
+    
+    fn v10_decrypt(encrypted: &[u8], has_host_hash: bool) -> Option<String> {
+        decrypt_linux_chromium_cookie(encrypted, &linux_chromium_key(b"peanuts"), None, has_host_hash)
+    }
+    
+    #[test]
+    fn test_read_chromium_v10_cookie() {
+        let dir = fixture_dir("v10");
+        let db = dir.join("Cookies");
+        let key = linux_chromium_key(b"peanuts");
+        chromium_db(&db, "23", &encrypt(b"v10", &key, COOKIE.as_bytes()));
+        
+        assert_eq!(read_chromium_cookies(&db, v10_decrypt), vec![(COOKIE.to_string(), Some(1_893_456_000))]);
+        fs::remove_dir_all(&dir).ok();
+    }
+    
+    #[test]
+    fn test_read_chromium_v11_cookie_with_host_hash() {
+        let dir = fixture_dir("v11");
+        let db = dir.join("Cookies");
+        let v10_key = linux_chromium_key(b"peanuts");
+        let v11_key = linux_chromium_key(b"keyring-password");
+        
+        let mut plaintext = vec![0xAB; 32];
+        plaintext.extend_from_slice(COOKIE.as_bytes());
+        chromium_db(&db, "24", &encrypt(b"v11", &v11_key, &plaintext));
+        
+        // Without the keyring password the value can't be read
+        assert!(read_chromium_cookies(&db, v10_decrypt).is_empty());
+        
+        let found = read_chromium_cookies(&db, |encrypted, has_host_hash| {
+            decrypt_linux_chromium_cookie(encrypted, &v10_key, Some(&v11_key), has_host_hash)
+        });
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0, COOKIE);
+        fs::remove_dir_all(&dir).ok();
+    }
+    
+    #[test]
+    fn test_scan_chromium_browser_covers_every_profile() {
+        let user_data = fixture_dir("profiles");
+        let key = linux_chromium_key(b"peanuts");
+        chromium_db(&user_data.join("Default/Cookies"), "23", &encrypt(b"v10", &key, b"first"));
+        chromium_db(&user_data.join("Profile 2/Network/Cookies"), "23", &encrypt(b"v10", &key, b"second"));
+        fs::create_dir_all(user_data.join("System Profile")).unwrap();
+        // A stray top-level database doesn't become a second Default, and links are skipped
+        chromium_db(&user_data.join("Cookies"), "23", &encrypt(b"v10", &key, b"stray"));
+        std::os::unix::fs::symlink(user_data.join("Profile 2"), user_data.join("Profile 3")).unwrap();
+        let outside = fixture_dir("profiles_outside");
+        chromium_db(&outside.join("Cookies"), "23", &encrypt(b"v10", &key, b"outside"));
+        std::os::unix::fs::symlink(&outside, user_data.join("Profile 4")).unwrap();
+        
+        let found = scan_chromium_browser("Brave", &user_data, v10_decrypt);
+        let summary: Vec<_> = found.iter().map(|c| (c.profile.as_str(), c.cookie.as_str())).collect();
+        assert_eq!(summary, vec![("Default", "first"), ("Profile 2", "second")]);
+        assert_eq!(found[1].source(), "Brave · Profile 2");
+        fs::remove_dir_all(&user_data).ok();
+        fs::remove_dir_all(&outside).ok();
+    }
+    
+    #[test]
+    fn test_scan_firefox_profiles() {
+        let profiles = fixture_dir("firefox");
+        let outside = fixture_dir("firefox_outside");
+        for (dir, value) in [(profiles.join("a1.default"), "old"), (profiles.join("b2.default-release"), COOKIE), (outside.clone(), "outside")] {
+            fs::create_dir_all(&dir).unwrap();
+            let conn = Connection::open(dir.join("cookies.sqlite")).unwrap();
+            conn.execute_batch(
+                "CREATE TABLE moz_cookies (host TEXT, name TEXT, value TEXT, expiry INTEGER, lastAccessed INTEGER);"
+            ).unwrap();
+            conn.execute("INSERT INTO moz_cookies VALUES ('.roblox.com', '.ROBLOSECURITY', ?1, 1893456000000, 2)", [value]).unwrap();
+        }
+        
+        // A profile linked in from elsewhere isn't read
+        std::os::unix::fs::symlink(&outside, profiles.join("c3.linked")).unwrap();
+        
+        let found = scan_firefox_profiles(&profiles);
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].profile, "a1.default");
+        assert_eq!(found[1].cookie, COOKIE);
+        assert_eq!(found[1].expires, Some(1_893_456_000));
+        assert!(!found[1].is_expired());
+        fs::remove_dir_all(&profiles).ok();
+        fs::remove_dir_all(&outside).ok();
+    }
+    
+    #[test]
+    fn test_found_cookie_status() {
+        let mut cookie = FoundCookie::new(COOKIE.to_string(), "Brave", "Default", Some(1_000));
+        assert_eq!(cookie.status_label(), "Checking...");
+        // Expired sessions are never sent to Roblox
+        cookie.validate();
+        assert_eq!(cookie.check, CookieCheck::Expired);
+        assert_eq!(cookie.status_label(), "Expired");
+        
+        cookie.check = CookieCheck::Unknown;
+        assert_eq!(cookie.status_label(), "Unknown");
+        cookie.username = Some("builderman".to_string());
+        assert_eq!(cookie.status_label(), "builderman");
+    }
+    
+    #[test]
+    fn test_is_own_file_rejects_paths_outside_root() {
+        let root = fixture_dir("owner_root");
+        let outside = fixture_dir("owner_outside");
+        fs::write(root.join("Cookies"), b"").unwrap();
+        fs::write(outside.join("Cookies"), b"").unwrap();
+        std::os::unix::fs::symlink(outside.join("Cookies"), root.join("Linked")).unwrap();
+        
+        assert!(is_own_file(&root.join("Cookies"), &root));
+        assert!(!is_own_file(&outside.join("Cookies"), &root));
+        assert!(!is_own_file(&root.join("Linked"), &root));
+        
+        fs::remove_dir_all(&root).ok();
+        fs::remove_dir_all(&outside).ok();
     }
 }
@@ -0,0 +1,200 @@
+/// Name of the Roblox session cookie
+const COOKIE_NAME: &str = ".ROBLOSECURITY";
+/// Every `.ROBLOSECURITY` value starts with this warning
+const WARNING_PREFIX: &str = "_|WARNING:-DO-NOT-SHARE";
+
+/// Pull every `.ROBLOSECURITY` value out of pasted or dropped text, in order and without duplicates.
+///
+/// Accepts a raw cookie value, a `Cookie:` header, a Netscape `cookies.txt` file,
+/// or a JSON export from extensions such as EditThisCookie and Cookie-Editor.
+/// Files holding several accounts yield one entry per account.
+pub fn parse_cookies(input: &str) -> Vec<String> {
+    let input = input.trim().trim_start_matches('\u{feff}');
+    let mut cookies = Vec::new();
+    
+    if input.starts_with('[') || input.starts_with('{') {
+        if let Ok(json) = serde_json::from_str::<serde_json::Value>(input) {
+            collect_json_cookies(&json, &mut cookies);
+            return dedup(cookies);
+        }
+    }
+    
+    for line in input.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        
+        if let Some(cookie) = parse_netscape_line(line) {
+            cookies.push(cookie);
+        } else if line.contains(&format!("{}=", COOKIE_NAME)) {
+            cookies.extend(parse_header_line(line));
+        } else {
+            cookies.extend(find_raw_values(line));
+        }
+    }
+    
+    // A bare value without the warning prefix, e.g. copied from dev tools with it stripped
+    if cookies.is_empty() && is_bare_value(input) {
+        cookies.push(input.to_string());
+    }
+    
+    dedup(cookies)
+}
+
+/// First `.ROBLOSECURITY` value in the input, if any
+pub fn parse_cookie(input: &str) -> Option<String> {
+    parse_cookies(input).into_iter().next()
+}
+
+/// Walk any JSON shape: a flat cookie list, `{"cookies": [...]}`, or one list per account
+fn collect_json_cookies(value: &serde_json::Value, out: &mut Vec<String>) {
+    match value {
+        serde_json::Value::Array(items) => {
+            for item in items {
+                collect_json_cookies(item, out);
+            }
+        }
+        serde_json::Value::Object(map) => {
+            let name = map.get("name").or_else(|| map.get("Name")).and_then(|v| v.as_str());
+            let value = map.get("value").or_else(|| map.get("Value")).and_then(|v| v.as_str());
+            
+            if let (Some(COOKIE_NAME), Some(value)) = (name, value) {
+                if !value.is_empty() {
+                    out.push(value.to_string());
+                }
+                return;
+            }
+            
+            for child in map.values() {
+                collect_json_cookies(child, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// `domain  flag  path  secure  expiry  name  value`, tab separated.
+/// Lines starting with `#HttpOnly_` are cookies; other `#` lines are comments.
+fn parse_netscape_line(line: &str) -> Option<String> {
+    let line = line.strip_prefix("#HttpOnly_").unwrap_or(line);
+    if line.starts_with('#') {
+        return None;
+    }
+    
+    let fields: Vec<&str> = line.split('\t').collect();
+    if fields.len() < 7 || fields[5] != COOKIE_NAME {
+        return None;
+    }
+    
+    let value = fields[6].trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// `Cookie: a=b; .ROBLOSECURITY=...; c=d` or the same pairs without the header name
+fn parse_header_line(line: &str) -> Vec<String> {
+    let line = line.split_once(':')
+        .filter(|(name, _)| name.trim().eq_ignore_ascii_case("cookie") || name.trim().eq_ignore_ascii_case("set-cookie"))
+        .map(|(_, rest)| rest)
+        .unwrap_or(line);
+    
+    line.split(';')
+        .filter_map(|pair| pair.trim().split_once('='))
+        .filter(|(name, _)| name.trim() == COOKIE_NAME)
+        .map(|(_, value)| value.trim().trim_matches('"').to_string())
+        .filter(|value| !value.is_empty())
+        .collect()
+}
+
+/// Values embedded in arbitrary text, found by their warning prefix
+fn find_raw_values(line: &str) -> Vec<String> {
+    let mut values = Vec::new();
+    let mut rest = line;
+    
+    while let Some(start) = rest.find(WARNING_PREFIX) {
+        let candidate = &rest[start..];
+        // The warning itself is `_|WARNING...|_`; the token ends at the first separator after it
+        let body_start = candidate.find("|_").map(|i| i + 2).unwrap_or(candidate.len());
+        let end = candidate[body_start..]
+            .find(|c: char| c.is_whitespace() || matches!(c, ';' | '"' | '\'' | ',' | '<' | '>'))
+            .map(|i| body_start + i)
+            .unwrap_or(candidate.len());
+        
+        values.push(candidate[..end].to_string());
+        rest = &candidate[end..];
+    }
+    
+    values
+}
+
+fn is_bare_value(input: &str) -> bool {
+    input.len() >= 100
+        && !input.contains(char::is_whitespace)
+        && !input.contains(['=', ';', '{', '['])
+}
+
+fn dedup(cookies: Vec<String>) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    cookies.into_iter().filter(|c| seen.insert(c.clone())).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const A: &str = "_|WARNING:-DO-NOT-SHARE-THIS.--Sharing-this-will-allow-someone-to-log-in-as-you-and-to-steal-your-ROBUX-and-items.|_AAAA1111";
+    const B: &str = "_|WARNING:-DO-NOT-SHARE-THIS.--Sharing-this-will-allow-someone-to-log-in-as-you-and-to-steal-your-ROBUX-and-items.|_BBBB2222";
+    
+    #[test]
+    fn test_parse_raw_value() {
+        assert_eq!(parse_cookies(&format!("  {}\n", A)), vec![A]);
+        assert_eq!(parse_cookies(&format!("{}\n{}\n{}", A, B, A)), vec![A, B]);
+        assert_eq!(parse_cookies(&format!("account1:pass:{}", A)), vec![A]);
+        
+        let bare = "F".repeat(150);
+        assert_eq!(parse_cookie(&bare), Some(bare.clone()));
+        assert!(parse_cookies("hello world").is_empty());
+    }
+    
+    #[test]
+    fn test_parse_cookie_header() {
+        let header = format!("Cookie: RBXEventTrackerV2=x; .ROBLOSECURITY={}; GuestData=UserID=-1", A);
+        assert_eq!(parse_cookies(&header), vec![A]);
+        
+        let pairs = format!(".ROBLOSECURITY=\"{}\"; rbx-ip2=1", B);
+        assert_eq!(parse_cookies(&pairs), vec![B]);
+    }
+    
+    #[test]
+    fn test_parse_netscape_cookies_txt() {
+        let file = format!(
+            "# Netscape HTTP Cookie File\n\
+             .roblox.com\tTRUE\t/\tTRUE\t1893456000\tRBXSource\tabc\n\
+             #HttpOnly_.roblox.com\tTRUE\t/\tTRUE\t1893456000\t.ROBLOSECURITY\t{}\n\
+             .roblox.com\tTRUE\t/\tTRUE\t1893456000\t.ROBLOSECURITY\t{}\n",
+            A, B
+        );
+        assert_eq!(parse_cookies(&file), vec![A, B]);
+    }
+    
+    #[test]
+    fn test_parse_json_exports() {
+        // EditThisCookie / Cookie-Editor: a flat list
+        let flat = format!(
+            r#"[{{"domain":".roblox.com","name":"RBXSource","value":"x"}},{{"domain":".roblox.com","name":".ROBLOSECURITY","value":"{}","httpOnly":true}}]"#,
+            A
+        );
+        assert_eq!(parse_cookies(&flat), vec![A]);
+        
+        // Wrapped in an object
+        let wrapped = format!(r#"{{"url":"https://www.roblox.com","cookies":[{{"name":".ROBLOSECURITY","value":"{}"}}]}}"#, B);
+        assert_eq!(parse_cookies(&wrapped), vec![B]);
+        
+        // One cookie list per account
+        let multi = format!(
+            r#"[[{{"name":".ROBLOSECURITY","value":"{}"}}],[{{"name":".ROBLOSECURITY","value":"{}"}}]]"#,
+            A, B
+        );
+        assert_eq!(parse_cookies(&multi), vec![A, B]);
+    }
+}
@@ -1,8 +1,12 @@
 mod cookie_finder;
+mod cookie_parser;
 mod multi_instance;
+mod password;
 mod web_login;
 
-pub use cookie_finder::{CookieFinder, FoundCookie};
+pub use cookie_finder::{CookieCheck, CookieFinder, FoundCookie, ScanEvent};
+pub use cookie_parser::{parse_cookie, parse_cookies};
 pub use multi_instance::MultiInstanceManager;
-pub use web_login::{LoginResult, WebLoginSession};
+pub use password::generate_password;
+pub use web_login::{AccountBrowserSession, BrowserOptions, BrowserProfile, BrowserStatus, LoginResult, WebLoginSession};
 
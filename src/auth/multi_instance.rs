@@ -1,80 +1,37 @@
-use std::ptr;
-use std::ffi::CString;
-
-#[cfg(windows)]
-use winapi::um::synchapi::{CreateMutexA, ReleaseMutex, WaitForSingleObject};
-#[cfg(windows)]
-use winapi::um::handleapi::CloseHandle;
-#[cfg(windows)]
-use winapi::um::winbase::WAIT_OBJECT_0;
-#[cfg(windows)]
-use winapi::shared::minwindef::TRUE;
+use crate::platform::{Platform, SingletonLock};
+use std::sync::Arc;
 
+/// Holds Roblox's single-instance lock while multi-instance is on
 pub struct MultiInstanceManager {
-    #[cfg(windows)]
-    mutex_handle: Option<*mut std::ffi::c_void>,
-    enabled: bool,
+    platform: Arc<dyn Platform>,
+    lock: Option<SingletonLock>,
 }
 
-// Required for the raw pointer - we manage it safely
-unsafe impl Send for MultiInstanceManager {}
-unsafe impl Sync for MultiInstanceManager {}
-
 impl MultiInstanceManager {
-    pub fn new() -> Self {
+    pub fn new(platform: Arc<dyn Platform>) -> Self {
         Self {
-            #[cfg(windows)]
-            mutex_handle: None,
-            enabled: false,
+            platform,
+            lock: None,
         }
     }
 
     pub fn is_enabled(&self) -> bool {
-        self.enabled
+        self.lock.is_some()
     }
 
-    #[cfg(windows)]
     pub fn enable(&mut self) -> Result<(), String> {
-        if self.enabled {
-            return Ok(());
-        }
-
-        unsafe {
-            // Create/open the mutex that Roblox uses to prevent multiple instances
-            let mutex_name = CString::new("ROBLOX_singletonMutex").unwrap();
-            let handle = CreateMutexA(ptr::null_mut(), TRUE, mutex_name.as_ptr());
-
-            if handle.is_null() {
-                return Err("Failed to create mutex".to_string());
-            }
-
-            // Try to acquire the mutex
-            let wait_result = WaitForSingleObject(handle, 0);
-            
-            if wait_result == WAIT_OBJECT_0 {
-                self.mutex_handle = Some(handle as *mut std::ffi::c_void);
-                self.enabled = true;
-                Ok(())
-            } else {
-                CloseHandle(handle);
-                Err("Roblox is already running - close it first to enable multi-instance".to_string())
-            }
+        if self.lock.is_none() {
+            self.lock = Some(self.platform.acquire_singleton()?);
         }
+        Ok(())
     }
 
-    #[cfg(windows)]
     pub fn disable(&mut self) {
-        if let Some(handle) = self.mutex_handle.take() {
-            unsafe {
-                ReleaseMutex(handle as *mut _);
-                CloseHandle(handle as *mut _);
-            }
-        }
-        self.enabled = false;
+        self.lock = None;
     }
 
     pub fn toggle(&mut self) -> Result<bool, String> {
-        if self.enabled {
+        if self.is_enabled() {
             self.disable();
             Ok(false)
         } else {
@@ -82,25 +39,4 @@ impl MultiInstanceManager {
             Ok(true)
         }
     }
-
-    // Non-Windows stubs
-    #[cfg(not(windows))]
-    pub fn enable(&mut self) -> Result<(), String> {
-        Err("Multi-instance only supported on Windows".to_string())
-    }
-
-    #[cfg(not(windows))]
-    pub fn disable(&mut self) {}
-}
-
-impl Drop for MultiInstanceManager {
-    fn drop(&mut self) {
-        self.disable();
-    }
-}
-
-impl Default for MultiInstanceManager {
-    fn default() -> Self {
-        Self::new()
-    }
 }
@@ -0,0 +1,43 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const LOWER: &[u8] = b"abcdefghijkmnopqrstuvwxyz";
+const UPPER: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ";
+const DIGITS: &[u8] = b"23456789";
+const SYMBOLS: &[u8] = b"!@#$%^&*-_=+?";
+
+/// Random password of `length` characters with at least one lowercase letter,
+/// uppercase letter, digit and symbol. Look-alike characters (l, I, O, 0, 1) are left out.
+pub fn generate_password(length: usize) -> String {
+    let mut rng = rand::rngs::OsRng;
+    let classes = [LOWER, UPPER, DIGITS, SYMBOLS];
+    let all: Vec<u8> = classes.concat();
+    
+    let mut chars: Vec<u8> = classes.iter()
+        .map(|class| class[rng.gen_range(0..class.len())])
+        .collect();
+    while chars.len() < length {
+        chars.push(all[rng.gen_range(0..all.len())]);
+    }
+    chars.shuffle(&mut rng);
+    
+    String::from_utf8(chars).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_generate_password() {
+        for _ in 0..50 {
+            let password = generate_password(20);
+            assert_eq!(password.len(), 20);
+            assert!(password.bytes().any(|c| LOWER.contains(&c)));
+            assert!(password.bytes().any(|c| UPPER.contains(&c)));
+            assert!(password.bytes().any(|c| DIGITS.contains(&c)));
+            assert!(password.bytes().any(|c| SYMBOLS.contains(&c)));
+        }
+        assert_ne!(generate_password(20), generate_password(20));
+    }
+}
@@ -1,7 +1,7 @@
 use std::sync::mpsc::{channel, Receiver, Sender, TryRecvError};
 use std::sync::{Arc, Mutex};
 use std::thread;
-use std::collections::HashSet;
+use std::path::PathBuf;
 use futures::StreamExt;
 
 #[derive(Clone, Debug)]
@@ -16,26 +16,106 @@ pub enum LoginResult {
     Error(String),
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum BrowserStatus {
     Launching,
     WaitingForLogin,
     LoggedIn,
 }
 
+impl BrowserStatus {
+    pub fn label(&self) -> &str {
+        match self {
+            BrowserStatus::Launching => "Launching browser...",
+            BrowserStatus::WaitingForLogin => "Waiting for login...",
+            BrowserStatus::LoggedIn => "Logged in - importing account...",
+        }
+    }
+}
+
+/// Credentials typed into the login form before the user takes over
+#[derive(Clone)]
+pub struct LoginPrefill {
+    pub username: String,
+    pub password: String,
+}
+
+/// Which Chromium user-data directory a browser session runs in
+#[derive(Clone, Debug)]
+pub enum BrowserProfile {
+    /// Fresh directory under the temp dir, deleted when the session ends
+    Temporary,
+    /// Persistent directory kept per account under the config dir
+    Account(u64),
+}
+
+impl BrowserProfile {
+    fn data_dir(&self) -> PathBuf {
+        match self {
+            BrowserProfile::Temporary => {
+                let nanos = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map(|d| d.as_nanos())
+                    .unwrap_or(0);
+                std::env::temp_dir().join(format!("nexus-browser-{}-{}", std::process::id(), nanos))
+            }
+            BrowserProfile::Account(user_id) => profiles_dir().join(user_id.to_string()),
+        }
+    }
+}
+
+/// Directory holding the per-account browser profiles
+pub fn profiles_dir() -> PathBuf {
+    let mut path = dirs::config_dir().unwrap_or_else(|| PathBuf::from("."));
+    path.push("NexusAccountManager");
+    path.push("profiles");
+    path
+}
+
+/// How to launch Chromium for a session
+#[derive(Clone, Debug)]
+pub struct BrowserOptions {
+    /// Chrome/Chromium binary to use instead of auto-detection
+    pub executable: Option<PathBuf>,
+    pub profile: BrowserProfile,
+}
+
+impl BrowserOptions {
+    pub fn new(executable: &str, profile: BrowserProfile) -> Self {
+        let executable = executable.trim();
+        Self {
+            executable: if executable.is_empty() { None } else { Some(PathBuf::from(executable)) },
+            profile,
+        }
+    }
+}
+
 pub struct WebLoginSession {
     result_receiver: Receiver<LoginResult>,
+    status_receiver: Receiver<BrowserStatus>,
     cancel_flag: Arc<Mutex<bool>>,
 }
 
 impl WebLoginSession {
-    /// Start a new browser login session with existing user IDs to skip
-    pub fn start_with_existing_users(existing_user_ids: HashSet<u64>) -> Result<Self, String> {
+    /// Start a new browser login session
+    pub fn start_new(options: BrowserOptions) -> Result<Self, String> {
+        Self::start(options, None)
+    }
+    
+    /// Start a login session for a stored account with its username and password prefilled
+    pub fn start_relogin(options: BrowserOptions, username: &str, password: &str) -> Result<Self, String> {
+        let prefill = LoginPrefill {
+            username: username.to_string(),
+            password: password.to_string(),
+        };
+        Self::start(options, Some(prefill))
+    }
+    
+    fn start(options: BrowserOptions, prefill: Option<LoginPrefill>) -> Result<Self, String> {
         let (result_sender, result_receiver) = channel();
-        let (status_sender, _status_receiver) = channel();
+        let (status_sender, status_receiver) = channel();
         let cancel_flag = Arc::new(Mutex::new(false));
         let cancel_flag_clone = cancel_flag.clone();
-        let existing_ids = existing_user_ids.clone();
         
         thread::spawn(move || {
             let rt = tokio::runtime::Builder::new_current_thread()
@@ -44,16 +124,22 @@ impl WebLoginSession {
                 .expect("Failed to create tokio runtime");
             
             rt.block_on(async {
-                run_browser_session(result_sender, status_sender, cancel_flag_clone, existing_ids).await;
+                run_browser_session(result_sender, status_sender, cancel_flag_clone, options, prefill).await;
             });
         });
         
         Ok(Self {
             result_receiver,
+            status_receiver,
             cancel_flag,
         })
     }
     
+    /// Latest progress update since the last poll, if any
+    pub fn poll_status(&self) -> Option<BrowserStatus> {
+        self.status_receiver.try_iter().last()
+    }
+    
     pub fn try_get_result(&self) -> Option<LoginResult> {
         match self.result_receiver.try_recv() {
             Ok(result) => Some(result),
@@ -69,50 +155,160 @@ impl WebLoginSession {
     }
 }
 
+/// A browser signed in as a stored account by injecting its cookie
+pub struct AccountBrowserSession {
+    close_flag: Arc<Mutex<bool>>,
+    exit_receiver: Receiver<Result<(), String>>,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+impl AccountBrowserSession {
+    /// Launch a browser with `.ROBLOSECURITY` set and roblox.com/home open
+    pub fn open(options: BrowserOptions, cookie: &str) -> Result<Self, String> {
+        let (exit_sender, exit_receiver) = channel();
+        let close_flag = Arc::new(Mutex::new(false));
+        let close_flag_clone = close_flag.clone();
+        let cookie = cookie.to_string();
+        
+        let handle = thread::spawn(move || {
+            let rt = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("Failed to create tokio runtime");
+            
+            let result = rt.block_on(run_account_browser(options, cookie, close_flag_clone));
+            let _ = exit_sender.send(result);
+        });
+        
+        Ok(Self {
+            close_flag,
+            exit_receiver,
+            thread: Some(handle),
+        })
+    }
+    
+    /// Returns the outcome once the browser has closed, either by the user or via `close`
+    pub fn try_get_exit(&self) -> Option<Result<(), String>> {
+        match self.exit_receiver.try_recv() {
+            Ok(result) => Some(result),
+            Err(TryRecvError::Empty) => None,
+            Err(TryRecvError::Disconnected) => Some(Ok(())),
+        }
+    }
+    
+    pub fn close(&self) {
+        if let Ok(mut flag) = self.close_flag.lock() {
+            *flag = true;
+        }
+    }
+}
+
+impl Drop for AccountBrowserSession {
+    fn drop(&mut self) {
+        // Wait for the browser to shut down so it doesn't outlive the manager
+        self.close();
+        if let Some(handle) = self.thread.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+async fn run_account_browser(
+    options: BrowserOptions,
+    cookie: String,
+    close_flag: Arc<Mutex<bool>>,
+) -> Result<(), String> {
+    use chromiumoxide::cdp::browser_protocol::network::CookieParam;
+    
+    let data_dir = options.profile.data_dir();
+    let is_temporary = matches!(options.profile, BrowserProfile::Temporary);
+    
+    let (mut browser, handler_task) = match launch_browser(&options, &data_dir).await {
+        Ok(b) => b,
+        Err(e) => {
+            remove_profile_dir(&data_dir, is_temporary);
+            return Err(e);
+        }
+    };
+    
+    let opened: Result<(), String> = async {
+        browser.clear_cookies().await.map_err(|e| format!("Failed to clear cookies: {}", e))?;
+        
+        let security_cookie = CookieParam::builder()
+            .name(".ROBLOSECURITY")
+            .value(cookie)
+            .domain(".roblox.com")
+            .path("/")
+            .secure(true)
+            .http_only(true)
+            .build()?;
+        browser.set_cookies(vec![security_cookie]).await
+            .map_err(|e| format!("Failed to set cookie: {}", e))?;
+        
+        browser.new_page("https://www.roblox.com/home").await
+            .map_err(|e| format!("Failed to open page: {}", e))?;
+        Ok(())
+    }.await;
+    
+    if opened.is_ok() {
+        // The handler stream ends once the user closes the browser window
+        while !handler_task.is_finished() {
+            if let Ok(closed) = close_flag.lock() {
+                if *closed {
+                    break;
+                }
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+        }
+    }
+    
+    let _ = browser.close().await;
+    let _ = browser.wait().await;
+    handler_task.abort();
+    remove_profile_dir(&data_dir, is_temporary);
+    opened
+}
+
 async fn run_browser_session(
     result_sender: Sender<LoginResult>,
     status_sender: Sender<BrowserStatus>,
     cancel_flag: Arc<Mutex<bool>>,
-    existing_user_ids: HashSet<u64>,
+    options: BrowserOptions,
+    prefill: Option<LoginPrefill>,
 ) {
-    use chromiumoxide::browser::{Browser, BrowserConfig};
-    
     let _ = status_sender.send(BrowserStatus::Launching);
     
-    let config = match BrowserConfig::builder()
-        .window_size(900, 700)
-        .with_head() 
-        .build()
-    {
-        Ok(c) => c,
-        Err(e) => {
-            let _ = result_sender.send(LoginResult::Error(format!("Failed to configure browser: {}", e)));
-            return;
-        }
-    };
+    let data_dir = options.profile.data_dir();
+    let is_temporary = matches!(options.profile, BrowserProfile::Temporary);
     
-    let (mut browser, mut handler) = match Browser::launch(config).await {
+    let (mut browser, handler_task) = match launch_browser(&options, &data_dir).await {
         Ok(b) => b,
         Err(e) => {
-            let _ = result_sender.send(LoginResult::Error(format!("Failed to launch browser: {}. Make sure Chrome/Chromium is installed.", e)));
+            let _ = result_sender.send(LoginResult::Error(e));
+            remove_profile_dir(&data_dir, is_temporary);
             return;
         }
     };
     
-    let handler_task = tokio::spawn(async move {
-        while let Some(_event) = handler.next().await {
-            
-        }
-    });
+    // Start from an empty cookie jar so a stale session can't be picked up
+    let _ = browser.clear_cookies().await;
     
     let page = match browser.new_page("https://www.roblox.com/login").await {
         Ok(p) => p,
         Err(e) => {
             let _ = result_sender.send(LoginResult::Error(format!("Failed to open page: {}", e)));
+            let _ = browser.close().await;
+            handler_task.abort();
+            remove_profile_dir(&data_dir, is_temporary);
             return;
         }
     };
     
+    if let Some(ref prefill) = prefill {
+        // Best effort - if the form never shows up the user can still type manually
+        let _ = fill_login_form(&page, prefill).await;
+    }
+    
     let _ = status_sender.send(BrowserStatus::WaitingForLogin);
     
     let check_interval = std::time::Duration::from_millis(1500);
@@ -139,32 +335,23 @@ async fn run_browser_session(
                     // Found the security cookie! Validate it
                     let cookie_value = cookie.value.clone();
                     
-                    match crate::api::RobloxApi::validate_cookie(&cookie_value) {
-                        Ok((user_id, display_name)) => {
-                            // Skip if we already have this user
-                            if existing_user_ids.contains(&user_id) {
-                                // Already have this account, keep waiting for a different login
-                                continue;
-                            }
-                            
-                            let _ = status_sender.send(BrowserStatus::LoggedIn);
-                            
-                            let username = crate::api::RobloxApi::get_username_by_id(user_id)
-                                .unwrap_or_else(|_| display_name.clone());
-                            
-                            let _ = result_sender.send(LoginResult::Success {
-                                cookie: cookie_value,
-                                user_id,
-                                username,
-                                display_name,
-                            });
-                            
-                            let _ = browser.close().await;
-                            handler_task.abort();
-                            return;
-                        }
-                        Err(_) => {
-                        }
+                    if let Ok((user_id, display_name)) = crate::api::RobloxApi::validate_cookie(&cookie_value) {
+                        let _ = status_sender.send(BrowserStatus::LoggedIn);
+                        
+                        let username = crate::api::RobloxApi::get_username_by_id(user_id)
+                            .unwrap_or_else(|_| display_name.clone());
+                        
+                        let _ = result_sender.send(LoginResult::Success {
+                            cookie: cookie_value,
+                            user_id,
+                            username,
+                            display_name,
+                        });
+                        
+                        let _ = browser.close().await;
+                        handler_task.abort();
+                        remove_profile_dir(&data_dir, is_temporary);
+                        return;
                     }
                 }
             }
@@ -176,4 +363,74 @@ async fn run_browser_session(
     // Cleanup
     let _ = browser.close().await;
     handler_task.abort();
+    remove_profile_dir(&data_dir, is_temporary);
+}
+
+/// Launch a headed Chromium on the given user-data directory and spawn its event handler
+async fn launch_browser(
+    options: &BrowserOptions,
+    data_dir: &std::path::Path,
+) -> Result<(chromiumoxide::Browser, tokio::task::JoinHandle<()>), String> {
+    use chromiumoxide::browser::{Browser, BrowserConfig};
+    
+    std::fs::create_dir_all(data_dir)
+        .map_err(|e| format!("Failed to create browser profile: {}", e))?;
+    
+    let mut builder = BrowserConfig::builder()
+        .window_size(900, 700)
+        .user_data_dir(data_dir)
+        .with_head();
+    
+    if let Some(ref exe) = options.executable {
+        builder = builder.chrome_executable(exe);
+    }
+    
+    let config = builder.build()
+        .map_err(|e| format!("Failed to configure browser: {}", e))?;
+    
+    let (browser, mut handler) = Browser::launch(config).await
+        .map_err(|e| format!("Failed to launch browser: {}. Make sure Chrome/Chromium is installed or set its path in Settings.", e))?;
+    
+    let handler_task = tokio::spawn(async move {
+        while let Some(_event) = handler.next().await {
+            
+        }
+    });
+    
+    Ok((browser, handler_task))
+}
+
+fn remove_profile_dir(data_dir: &std::path::Path, is_temporary: bool) {
+    if is_temporary {
+        let _ = std::fs::remove_dir_all(data_dir);
+    }
+}
+
+/// Type the stored credentials into the roblox.com login form.
+/// The form is rendered client-side, so poll for the inputs for a few seconds.
+async fn fill_login_form(page: &chromiumoxide::Page, prefill: &LoginPrefill) -> Result<(), String> {
+    let mut attempts = 0;
+    let username_input = loop {
+        match page.find_element("#login-username").await {
+            Ok(el) => break el,
+            Err(e) => {
+                attempts += 1;
+                if attempts >= 20 {
+                    return Err(format!("Login form not found: {}", e));
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+            }
+        }
+    };
+    
+    username_input.click().await.map_err(|e| e.to_string())?;
+    username_input.type_str(&prefill.username).await.map_err(|e| e.to_string())?;
+    
+    if !prefill.password.is_empty() {
+        let password_input = page.find_element("#login-password").await.map_err(|e| e.to_string())?;
+        password_input.click().await.map_err(|e| e.to_string())?;
+        password_input.type_str(&prefill.password).await.map_err(|e| e.to_string())?;
+    }
+    
+    Ok(())
 }
@@ -4,6 +4,8 @@ mod account;
 mod api;
 mod auth;
 mod games;
+mod platform;
+mod protocol;
 mod theme;
 mod ui;
 
@@ -59,7 +61,29 @@ mod win_utils {
     }
 }
 
+/// Value of `--preset NAME` or `--preset=NAME`
+fn preset_arg(mut args: impl Iterator<Item = String>) -> Option<String> {
+    while let Some(arg) = args.next() {
+        if arg == "--preset" {
+            return args.next();
+        }
+        if let Some(name) = arg.strip_prefix("--preset=") {
+            return Some(name.to_string());
+        }
+    }
+    None
+}
+
 fn main() -> Result<(), eframe::Error> {
+    // A second launch, e.g. from a nexus:// link, passes its link and preset to the running window
+    let link = protocol::link_arg(std::env::args().skip(1));
+    let preset = preset_arg(std::env::args().skip(1));
+    let listener = match protocol::claim_instance(&protocol::handoffs(link.clone(), preset.clone())) {
+        protocol::Instance::HandedOff => return Ok(()),
+        protocol::Instance::First(listener) => Some(listener),
+        protocol::Instance::Solo => None,
+    };
+    
     #[cfg(windows)]
     let tray_menu = {
         let menu = Menu::new();
@@ -133,10 +157,26 @@ fn main() -> Result<(), eframe::Error> {
     eframe::run_native(
         "Nexus Account Manager",
         options,
-        Box::new(|cc| {
+        Box::new(move |cc| {
             egui_extras::install_image_loaders(&cc.egui_ctx);
             theme::setup_dark_theme(&cc.egui_ctx);
-            Ok(Box::new(NexusApp::new()))
+            let mut app = NexusApp::new();
+            app.pending_presets.extend(preset);
+            app.pending_links.extend(link);
+            app.handoff = listener.map(|listener| {
+                let ctx = cc.egui_ctx.clone();
+                protocol::listen(listener, move |handoff| {
+                    #[cfg(windows)]
+                    if *handoff == protocol::Handoff::Show {
+                        MINIMIZED_TO_TRAY.store(false, Ordering::SeqCst);
+                        win_utils::show_window();
+                    }
+                    #[cfg(not(windows))]
+                    let _ = handoff;
+                    ctx.request_repaint();
+                })
+            });
+            Ok(Box::new(app))
         }),
     )
 }
@@ -226,7 +266,40 @@ impl eframe::App for NexusApp {
             self.refresh_presence_only();
         }
         
+        if self.check_handoff() {
+            ctx.send_viewport_cmd(egui::ViewportCommand::Visible(true));
+            ctx.send_viewport_cmd(egui::ViewportCommand::Minimized(false));
+            ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
+        }
+        // Each becomes the frame's action, so take one per frame
+        if let Some(name) = self.pending_presets.pop_front() {
+            self.launch_preset_named(&name);
+        } else if let Some(link) = self.pending_links.pop_front() {
+            self.action = ui::Action::OpenNexusLink(link);
+        }
+        if !self.pending_presets.is_empty() || !self.pending_links.is_empty() {
+            ctx.request_repaint();
+        }
+        
         self.check_browser_login_result();
+        self.check_account_browsers();
+        self.check_cookie_scan();
+        self.check_info_fetch();
+        self.check_account_fetches();
+        self.check_password_rotation();
+        self.check_privacy_run();
+        self.check_block_copy();
+        if self.has_background_work() {
+            ctx.request_repaint_after(std::time::Duration::from_millis(200));
+        }
+        self.check_launch_queue();
+        if self.launch_queue.is_active() {
+            ctx.request_repaint_after(std::time::Duration::from_millis(200));
+        }
+        self.check_clients();
+        if self.client_tracker.has_work() {
+            ctx.request_repaint_after(std::time::Duration::from_secs(2));
+        }
         
         ctx.input(|i| {
             if !i.raw.dropped_files.is_empty() {
@@ -234,20 +307,28 @@ impl eframe::App for NexusApp {
                     if let Some(ref bytes) = file.bytes {
                         if let Ok(content) = String::from_utf8(bytes.to_vec()) {
                             let trimmed = content.trim();
-                            if trimmed.contains("_|WARNING:-DO-NOT-SHARE") || trimmed.len() > 100 {
+                            if !auth::parse_cookies(trimmed).is_empty() {
                                 self.action = ui::Action::ImportDroppedCookie(trimmed.to_string());
                                 self.drag_drop_active = false;
                                 return;
                             }
+                            if let Some(target) = api::find_link(trimmed) {
+                                self.action = ui::Action::OpenLink(target);
+                                return;
+                            }
                         }
                     } else if let Some(ref path) = file.path {
                         if let Ok(content) = std::fs::read_to_string(path) {
                             let trimmed = content.trim();
-                            if trimmed.contains("_|WARNING:-DO-NOT-SHARE") || trimmed.len() > 100 {
+                            if !auth::parse_cookies(trimmed).is_empty() {
                                 self.action = ui::Action::ImportDroppedCookie(trimmed.to_string());
                                 self.drag_drop_active = false;
                                 return;
                             }
+                            if let Some(target) = api::find_link(trimmed) {
+                                self.action = ui::Action::OpenLink(target);
+                                return;
+                            }
                         }
                     }
                 }
@@ -371,7 +452,7 @@ impl eframe::App for NexusApp {
                     ui.put(
                         screen_rect,
                         egui::Label::new(
-                            RichText::new("🍪  Drop Cookie File Here")
+                            RichText::new("🍪  Drop Cookie File or Roblox Link")
                                 .size(26.0)
                                 .color(egui::Color32::WHITE)
                                 .strong()
@@ -420,6 +501,7 @@ impl eframe::App for NexusApp {
                             Tab::Servers => self.render_servers_tab(ui),
                             Tab::AccountUtils => self.render_account_utils_tab(ui),
                             Tab::ImportCookie => self.render_import_cookie_tab(ui),
+                            Tab::AccountDetails => self.render_account_details_tab(ui),
                             Tab::Settings => self.render_settings_tab(ui),
                             Tab::About => self.render_about_tab(ui),
                         }
@@ -437,5 +519,17 @@ impl eframe::App for NexusApp {
         if self.server_browser.vip_access_code_show {
             self.render_vip_access_code_modal(ctx);
         }
+        
+        if self.launch_queue_show {
+            self.render_launch_queue(ctx);
+        }
+        
+        if self.preset_draft.is_some() {
+            self.render_preset_editor(ctx);
+        }
+    }
+    
+    fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+        self.close_account_browsers();
     }
 }
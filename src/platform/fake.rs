@@ -0,0 +1,88 @@
+use super::{Platform, SingletonLock};
+use crate::api::LaunchOptions;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// In-memory platform for tests. Every opened URI "starts" a client with a new PID.
+#[derive(Default)]
+pub struct FakePlatform {
+    state: Arc<Mutex<FakeState>>,
+}
+
+#[derive(Default)]
+pub struct FakeState {
+    pub opened: Vec<String>,
+    pub running: HashSet<u32>,
+    pub kill_all_calls: usize,
+    pub singleton_held: bool,
+    pub scheme_registered: bool,
+    pub next_pid: u32,
+}
+
+struct FakeLock(Arc<Mutex<FakeState>>);
+
+impl Drop for FakeLock {
+    fn drop(&mut self) {
+        self.0.lock().unwrap().singleton_held = false;
+    }
+}
+
+impl FakePlatform {
+    /// PIDs start at `first_pid` so several fakes never hand out the same one
+    pub fn starting_at(first_pid: u32) -> Self {
+        let platform = Self::default();
+        platform.state().next_pid = first_pid;
+        platform
+    }
+    
+    pub fn state(&self) -> MutexGuard<'_, FakeState> {
+        self.state.lock().unwrap()
+    }
+}
+
+impl Platform for FakePlatform {
+    fn name(&self) -> &'static str {
+        "Fake"
+    }
+    
+    fn open_uri(&self, uri: &str, _options: &LaunchOptions) -> Result<(), String> {
+        let mut state = self.state();
+        state.opened.push(uri.to_string());
+        let pid = state.next_pid;
+        state.next_pid += 1;
+        state.running.insert(pid);
+        Ok(())
+    }
+    
+    fn client_pids(&self) -> HashSet<u32> {
+        self.state().running.clone()
+    }
+    
+    fn kill_process(&self, pid: u32) -> Result<(), String> {
+        if self.state().running.remove(&pid) {
+            Ok(())
+        } else {
+            Err(format!("No process {}", pid))
+        }
+    }
+    
+    fn kill_all_clients(&self, _options: &LaunchOptions) {
+        let mut state = self.state();
+        state.running.clear();
+        state.kill_all_calls += 1;
+    }
+    
+    fn acquire_singleton(&self) -> Result<SingletonLock, String> {
+        let mut state = self.state();
+        if state.singleton_held {
+            return Err("Roblox is already running - close it first to enable multi-instance".to_string());
+        }
+        state.singleton_held = true;
+        Ok(Box::new(FakeLock(self.state.clone())))
+    }
+    
+    fn register_uri_scheme(&self) -> Result<(), String> {
+        self.state().scheme_registered = true;
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+use super::{is_client_name, Platform, SingletonLock};
+use crate::api::launcher::command_args;
+use crate::api::{LaunchOptions, LauncherBackend};
+use crate::protocol::SCHEME;
+use std::collections::HashSet;
+use std::process::Command;
+use std::time::Duration;
+
+const DESKTOP_FILE: &str = "nexus-account-manager.desktop";
+
+/// Linux and other Unix desktops, launching through the configured `LauncherBackend`
+pub struct LinuxPlatform;
+
+impl Platform for LinuxPlatform {
+    fn name(&self) -> &'static str {
+        "Linux"
+    }
+    
+    fn open_uri(&self, uri: &str, options: &LaunchOptions) -> Result<(), String> {
+        let args = match options.backend {
+            LauncherBackend::XdgOpen => {
+                let opener = if cfg!(target_os = "macos") { "open" } else { "xdg-open" };
+                vec![opener.to_string(), uri.to_string()]
+            }
+            LauncherBackend::Flatpak(ref app_id) => {
+                let app_id = app_id.trim();
+                if app_id.is_empty() {
+                    return Err("No Flatpak app ID set in Settings".to_string());
+                }
+                vec!["flatpak".to_string(), "run".to_string(), app_id.to_string(), uri.to_string()]
+            }
+            LauncherBackend::Command(ref template) => command_args(template, uri, &options.account)?,
+        };
+        
+        Command::new(&args[0])
+            .args(&args[1..])
+            .spawn()
+            .map_err(|e| format!("Failed to run {}: {}", args[0], e))?;
+        Ok(())
+    }
+    
+    fn client_pids(&self) -> HashSet<u32> {
+        if cfg!(target_os = "macos") {
+            return Command::new("pgrep")
+                .args(["-x", "RobloxPlayer"])
+                .output()
+                .map(|out| String::from_utf8_lossy(&out.stdout).lines().filter_map(|l| l.trim().parse().ok()).collect())
+                .unwrap_or_default();
+        }
+        
+        let Ok(entries) = std::fs::read_dir("/proc") else {
+            return HashSet::new();
+        };
+        entries
+            .flatten()
+            .filter_map(|entry| {
+                let pid: u32 = entry.file_name().to_str()?.parse().ok()?;
+                let comm = std::fs::read_to_string(entry.path().join("comm")).ok()?;
+                is_client_name(comm.trim()).then_some(pid)
+            })
+            .collect()
+    }
+    
+    fn kill_process(&self, pid: u32) -> Result<(), String> {
+        let out = Command::new("kill")
+            .arg(pid.to_string())
+            .output()
+            .map_err(|e| format!("Failed to close client: {}", e))?;
+        if out.status.success() {
+            Ok(())
+        } else {
+            Err(String::from_utf8_lossy(&out.stderr).trim().to_string())
+        }
+    }
+    
+    /// Only a Flatpak client can be told to quit; other launchers manage their own instances
+    fn kill_all_clients(&self, options: &LaunchOptions) {
+        if let LauncherBackend::Flatpak(ref app_id) = options.backend {
+            if !app_id.trim().is_empty() {
+                Command::new("flatpak").args(["kill", app_id.trim()]).output().ok();
+                std::thread::sleep(Duration::from_millis(500));
+            }
+        }
+    }
+    
+    /// Clients here don't use a system-wide mutex, so there is nothing to hold
+    fn acquire_singleton(&self) -> Result<SingletonLock, String> {
+        Ok(Box::new(()))
+    }
+    
+    /// Writes a hidden desktop entry claiming `x-scheme-handler/nexus` and makes it the default
+    fn register_uri_scheme(&self) -> Result<(), String> {
+        if cfg!(target_os = "macos") {
+            return Err("Link registration needs an app bundle on macOS".to_string());
+        }
+        
+        let exe = std::env::current_exe().map_err(|e| format!("Can't find the app's path: {}", e))?;
+        let dir = dirs::data_dir().ok_or("No data directory")?.join("applications");
+        std::fs::create_dir_all(&dir).map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+        
+        // Exec arguments are quoted, with the desktop entry escapes inside the quotes
+        let exec = exe.display().to_string().chars().fold(String::new(), |mut out, c| {
+            if matches!(c, '"' | '`' | '$' | '\\') {
+                out.push('\\');
+            }
+            out.push(c);
+            out
+        });
+        let entry = format!(
+            "[Desktop Entry]\nType=Application\nName=Nexus Account Manager\nExec=\"{}\" %u\nTerminal=false\nNoDisplay=true\nMimeType=x-scheme-handler/{};\n",
+            exec, SCHEME
+        );
+        std::fs::write(dir.join(DESKTOP_FILE), entry).map_err(|e| format!("Failed to write desktop entry: {}", e))?;
+        
+        let status = Command::new("xdg-mime")
+            .args(["default", DESKTOP_FILE, &format!("x-scheme-handler/{}", SCHEME)])
+            .status()
+            .map_err(|e| format!("Failed to run xdg-mime: {}", e))?;
+        if !status.success() {
+            return Err("xdg-mime couldn't set the default handler".to_string());
+        }
+        Command::new("update-desktop-database").arg(&dir).output().ok();
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+#[cfg(test)]
+mod fake;
+#[cfg(not(windows))]
+mod linux;
+#[cfg(windows)]
+mod win32;
+
+#[cfg(test)]
+pub use fake::FakePlatform;
+#[cfg(not(windows))]
+pub use linux::LinuxPlatform;
+#[cfg(windows)]
+pub use win32::WindowsPlatform;
+
+use crate::api::LaunchOptions;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Keeps the single-instance lock held until dropped
+pub type SingletonLock = Box<dyn Send>;
+
+/// Everything launching needs from the OS: opening `roblox-player:` URIs, finding and
+/// closing client processes, and holding Roblox's single-instance lock
+pub trait Platform: Send + Sync {
+    fn name(&self) -> &'static str;
+    
+    /// Hand a `roblox-player:` URI to the client
+    fn open_uri(&self, uri: &str, options: &LaunchOptions) -> Result<(), String>;
+    
+    /// PIDs of every running Roblox client, whoever started it
+    fn client_pids(&self) -> HashSet<u32>;
+    
+    fn kill_process(&self, pid: u32) -> Result<(), String>;
+    
+    /// Close every running client before a single-instance launch
+    fn kill_all_clients(&self, options: &LaunchOptions);
+    
+    /// Take the lock Roblox uses to allow only one client, so any number can start
+    fn acquire_singleton(&self) -> Result<SingletonLock, String>;
+    
+    /// Make this executable the handler for `nexus://` links
+    fn register_uri_scheme(&self) -> Result<(), String>;
+}
+
+impl std::fmt::Debug for dyn Platform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The platform the app is running on
+pub fn native() -> Arc<dyn Platform> {
+    #[cfg(windows)]
+    return Arc::new(WindowsPlatform);
+    #[cfg(not(windows))]
+    return Arc::new(LinuxPlatform);
+}
+
+/// Process names a client shows up under on Linux: Sober, or the Windows player under Wine
+/// (`comm` is cut to 15 characters)
+#[cfg(any(not(windows), test))]
+fn is_client_name(comm: &str) -> bool {
+    let comm = comm.to_ascii_lowercase();
+    comm == "sober" || (comm.len() >= 12 && "robloxplayerbeta.exe".starts_with(&comm))
+}
+
+/// PIDs from `tasklist /FO CSV /NH` output
+#[cfg(any(windows, test))]
+fn parse_tasklist(output: &str) -> HashSet<u32> {
+    output.lines()
+        .filter_map(|line| line.split("\",\"").nth(1))
+        .filter_map(|pid| pid.trim_matches('"').parse().ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_process_matching() {
+        let output = "\"RobloxPlayerBeta.exe\",\"4120\",\"Console\",\"1\",\"412,004 K\"\r\n\
+                      \"RobloxPlayerBeta.exe\",\"9876\",\"Console\",\"1\",\"398,120 K\"\r\n";
+        assert_eq!(parse_tasklist(output), HashSet::from([4120, 9876]));
+        assert!(parse_tasklist("INFO: No tasks are running which match the specified criteria.").is_empty());
+        
+        assert!(is_client_name("sober"));
+        assert!(is_client_name("RobloxPlayerBet"));
+        assert!(!is_client_name("roblox"));
+        assert!(!is_client_name("bash"));
+    }
+}
@@ -0,0 +1,113 @@
+use super::{parse_tasklist, Platform, SingletonLock};
+use crate::api::LaunchOptions;
+use crate::protocol::SCHEME;
+use std::collections::HashSet;
+use std::ffi::CString;
+use std::os::windows::process::CommandExt;
+use std::process::Command;
+use std::ptr;
+use std::time::Duration;
+
+use winapi::shared::minwindef::TRUE;
+use winapi::um::handleapi::CloseHandle;
+use winapi::um::synchapi::{CreateMutexA, ReleaseMutex, WaitForSingleObject};
+use winapi::um::winbase::WAIT_OBJECT_0;
+use winreg::enums::HKEY_CURRENT_USER;
+use winreg::RegKey;
+
+const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+pub struct WindowsPlatform;
+
+/// Owned handle to `ROBLOX_singletonMutex`, released on drop
+struct MutexGuard(*mut std::ffi::c_void);
+
+// The handle is only touched again when the guard is dropped
+unsafe impl Send for MutexGuard {}
+
+impl Drop for MutexGuard {
+    fn drop(&mut self) {
+        unsafe {
+            ReleaseMutex(self.0 as *mut _);
+            CloseHandle(self.0 as *mut _);
+        }
+    }
+}
+
+impl Platform for WindowsPlatform {
+    fn name(&self) -> &'static str {
+        "Windows"
+    }
+    
+    fn open_uri(&self, uri: &str, _options: &LaunchOptions) -> Result<(), String> {
+        Command::new("cmd")
+            .args(["/C", "start", "", uri])
+            .creation_flags(CREATE_NO_WINDOW)
+            .spawn()
+            .map_err(|e| format!("Failed to launch: {}", e))?;
+        Ok(())
+    }
+    
+    fn client_pids(&self) -> HashSet<u32> {
+        Command::new("tasklist")
+            .args(["/FO", "CSV", "/NH", "/FI", "IMAGENAME eq RobloxPlayerBeta.exe"])
+            .creation_flags(CREATE_NO_WINDOW)
+            .output()
+            .map(|out| parse_tasklist(&String::from_utf8_lossy(&out.stdout)))
+            .unwrap_or_default()
+    }
+    
+    fn kill_process(&self, pid: u32) -> Result<(), String> {
+        let out = Command::new("taskkill")
+            .args(["/F", "/PID", &pid.to_string()])
+            .creation_flags(CREATE_NO_WINDOW)
+            .output()
+            .map_err(|e| format!("Failed to close client: {}", e))?;
+        if out.status.success() {
+            Ok(())
+        } else {
+            Err(String::from_utf8_lossy(&out.stderr).trim().to_string())
+        }
+    }
+    
+    fn kill_all_clients(&self, _options: &LaunchOptions) {
+        Command::new("taskkill").args(["/F", "/IM", "RobloxPlayerBeta.exe"]).creation_flags(CREATE_NO_WINDOW).output().ok();
+        Command::new("taskkill").args(["/F", "/IM", "Roblox.exe"]).creation_flags(CREATE_NO_WINDOW).output().ok();
+        std::thread::sleep(Duration::from_millis(500));
+    }
+    
+    fn acquire_singleton(&self) -> Result<SingletonLock, String> {
+        unsafe {
+            // Create/open the mutex that Roblox uses to prevent multiple instances
+            let mutex_name = CString::new("ROBLOX_singletonMutex").unwrap();
+            let handle = CreateMutexA(ptr::null_mut(), TRUE, mutex_name.as_ptr());
+            
+            if handle.is_null() {
+                return Err("Failed to create mutex".to_string());
+            }
+            
+            // Try to acquire the mutex
+            if WaitForSingleObject(handle, 0) == WAIT_OBJECT_0 {
+                Ok(Box::new(MutexGuard(handle as *mut std::ffi::c_void)))
+            } else {
+                CloseHandle(handle);
+                Err("Roblox is already running - close it first to enable multi-instance".to_string())
+            }
+        }
+    }
+    
+    /// Registers the scheme for the current user under `Software\Classes`
+    fn register_uri_scheme(&self) -> Result<(), String> {
+        let exe = std::env::current_exe().map_err(|e| format!("Can't find the app's path: {}", e))?;
+        let classes = RegKey::predef(HKEY_CURRENT_USER);
+        let registry_err = |e: std::io::Error| format!("Failed to write registry: {}", e);
+        
+        let (key, _) = classes.create_subkey(format!("Software\\Classes\\{}", SCHEME)).map_err(registry_err)?;
+        key.set_value("", &"URL:Nexus Account Manager").map_err(registry_err)?;
+        key.set_value("URL Protocol", &"").map_err(registry_err)?;
+        
+        let (command, _) = key.create_subkey("shell\\open\\command").map_err(registry_err)?;
+        command.set_value("", &format!("\"{}\" \"%1\"", exe.display())).map_err(registry_err)?;
+        Ok(())
+    }
+}
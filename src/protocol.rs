@@ -0,0 +1,315 @@
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::net::Shutdown;
+use std::sync::mpsc::{channel, Receiver};
+use std::time::Duration;
+
+/// Scheme registered for links like `nexus://launch?account=<user_id>&place=<id>&job=<id>`
+pub const SCHEME: &str = "nexus";
+
+/// Sent instead of a link when a second instance is started without one
+const SHOW: &str = "show";
+/// Starts a handoff carrying a `--preset` name
+const PRESET_PREFIX: &str = "preset:";
+
+/// A parsed `nexus://launch` link
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NexusLink {
+    /// User ID, or a username
+    pub account: String,
+    pub place_id: Option<u64>,
+    pub job_id: Option<String>,
+}
+
+impl NexusLink {
+    pub fn parse(uri: &str) -> Result<Self, String> {
+        let rest = uri.trim()
+            .strip_prefix(SCHEME)
+            .and_then(|r| r.strip_prefix(':'))
+            .ok_or_else(|| format!("Not a {}:// link", SCHEME))?;
+        let rest = rest.trim_start_matches('/');
+        let (action, query) = rest.split_once('?').unwrap_or((rest, ""));
+        
+        if !action.trim_end_matches('/').eq_ignore_ascii_case("launch") {
+            return Err(format!("Unknown link action: {}", action));
+        }
+        
+        let params: HashMap<String, String> = query.split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .map(|(key, value)| {
+                let value = urlencoding::decode(value).map(|v| v.into_owned()).unwrap_or_else(|_| value.to_string());
+                (key.to_ascii_lowercase(), value.trim().to_string())
+            })
+            .filter(|(_, value)| !value.is_empty())
+            .collect();
+        
+        let account = params.get("account").cloned().ok_or("Link doesn't name an account")?;
+        let place_id = match params.get("place") {
+            Some(place) => Some(place.parse().map_err(|_| format!("Invalid place ID: {}", place))?),
+            None => None,
+        };
+        let job_id = params.get("job").cloned();
+        if job_id.is_some() && place_id.is_none() {
+            return Err("A job ID needs a place ID".to_string());
+        }
+        
+        Ok(Self { account, place_id, job_id })
+    }
+}
+
+/// The `nexus://` link among the command line arguments, if any
+pub fn link_arg(mut args: impl Iterator<Item = String>) -> Option<String> {
+    args.find(|arg| arg.starts_with(&format!("{}:", SCHEME)))
+}
+
+/// How this process relates to other running copies
+pub enum Instance {
+    /// First one running; later instances hand their links to this listener
+    First(transport::Listener),
+    /// Couldn't listen or reach the running instance, so run without handoffs
+    Solo,
+    /// Another instance took the link, so this one should exit
+    HandedOff,
+}
+
+/// Become the running instance, or pass `handoffs` to the one already running
+pub fn claim_instance(handoffs: &[Handoff]) -> Instance {
+    match transport::bind() {
+        Ok(listener) => Instance::First(listener),
+        Err(_) => match send_to_running(handoffs) {
+            Ok(()) => Instance::HandedOff,
+            Err(_) => Instance::Solo,
+        },
+    }
+}
+
+fn send_to_running(handoffs: &[Handoff]) -> std::io::Result<()> {
+    let mut stream = transport::connect()?;
+    stream.set_read_timeout(Some(Duration::from_secs(2)))?;
+    for handoff in handoffs {
+        writeln!(stream, "{}", handoff.to_message())?;
+    }
+    stream.shutdown(Shutdown::Write)?;
+    // Wait for the acknowledgement so we know a manager, not some other program, got it
+    let mut reply = String::new();
+    BufReader::new(stream).read_line(&mut reply)?;
+    if reply.trim() == "ok" {
+        Ok(())
+    } else {
+        Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "Unexpected handoff reply"))
+    }
+}
+
+/// Something handed over by a later instance
+#[derive(Clone, Debug, PartialEq)]
+pub enum Handoff {
+    Show,
+    Link(String),
+    /// `--preset` name
+    Preset(String),
+}
+
+impl Handoff {
+    fn to_message(&self) -> String {
+        match self {
+            Handoff::Show => SHOW.to_string(),
+            Handoff::Link(link) => link.clone(),
+            Handoff::Preset(name) => format!("{}{}", PRESET_PREFIX, name),
+        }
+    }
+    
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        if line == SHOW {
+            Some(Handoff::Show)
+        } else if let Some(name) = line.strip_prefix(PRESET_PREFIX) {
+            Some(Handoff::Preset(name.to_string()))
+        } else if line.starts_with(&format!("{}:", SCHEME)) {
+            Some(Handoff::Link(line.to_string()))
+        } else {
+            None
+        }
+    }
+}
+
+/// What a second instance passes on: its link and preset, or a request to come forward
+pub fn handoffs(link: Option<String>, preset: Option<String>) -> Vec<Handoff> {
+    let handoffs: Vec<Handoff> = link.map(Handoff::Link).into_iter()
+        .chain(preset.map(Handoff::Preset))
+        .collect();
+    if handoffs.is_empty() {
+        vec![Handoff::Show]
+    } else {
+        handoffs
+    }
+}
+
+/// Accept handoffs on a background thread. `wake` is called with each one so the UI
+/// picks it up even while idle or hidden.
+pub fn listen(listener: transport::Listener, wake: impl Fn(&Handoff) + Send + 'static) -> Receiver<Handoff> {
+    let (sender, receiver) = channel();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            stream.set_read_timeout(Some(Duration::from_secs(2))).ok();
+            let mut lines: Vec<String> = BufReader::new(&stream).lines().map_while(Result::ok).collect();
+            if !listener.accept(&mut lines) {
+                continue;
+            }
+            
+            let handoffs: Vec<Handoff> = lines.iter().filter_map(|line| Handoff::parse(line)).collect();
+            if handoffs.is_empty() {
+                continue;
+            }
+            (&stream).write_all(b"ok\n").ok();
+            
+            for handoff in handoffs {
+                wake(&handoff);
+                if sender.send(handoff).is_err() {
+                    return;
+                }
+            }
+        }
+    });
+    receiver
+}
+
+/// Socket in a directory only this user can open, so other local users can't hand us links
+#[cfg(not(windows))]
+mod transport {
+    use std::fs;
+    use std::io;
+    use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::path::PathBuf;
+    
+    pub struct Listener(UnixListener);
+    
+    impl Listener {
+        pub fn incoming(&self) -> impl Iterator<Item = UnixStream> + '_ {
+            self.0.incoming().flatten()
+        }
+        
+        /// Only this user can connect, so every connection is trusted
+        pub fn accept(&self, _lines: &mut Vec<String>) -> bool {
+            true
+        }
+    }
+    
+    fn socket_path() -> io::Result<PathBuf> {
+        let dir = dirs::runtime_dir()
+            .or_else(dirs::cache_dir)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "No runtime directory"))?
+            .join("NexusAccountManager");
+        fs::DirBuilder::new().recursive(true).mode(0o700).create(&dir)?;
+        // Fails unless the directory is ours, and closes it up if it was made some other way
+        fs::set_permissions(&dir, fs::Permissions::from_mode(0o700))?;
+        Ok(dir.join("handoff.sock"))
+    }
+    
+    pub fn bind() -> io::Result<Listener> {
+        let path = socket_path()?;
+        match UnixListener::bind(&path) {
+            // Left behind by an instance that didn't shut down cleanly
+            Err(e) if e.kind() == io::ErrorKind::AddrInUse && UnixStream::connect(&path).is_err() => {
+                fs::remove_file(&path)?;
+                UnixListener::bind(&path).map(Listener)
+            }
+            result => result.map(Listener),
+        }
+    }
+    
+    pub fn connect() -> io::Result<UnixStream> {
+        UnixStream::connect(socket_path()?)
+    }
+}
+
+/// Loopback port, guarded by a token in a file only this user can read. Senders echo the
+/// token first so other local users can't hand us links.
+#[cfg(windows)]
+mod transport {
+    use rand::distributions::{Alphanumeric, DistString};
+    use std::fs;
+    use std::io::{self, Write};
+    use std::net::{Ipv4Addr, TcpListener, TcpStream};
+    use std::path::PathBuf;
+    use std::time::Duration;
+    
+    const HANDOFF_PORT: u16 = 47_219;
+    
+    pub struct Listener {
+        tcp: TcpListener,
+        token: String,
+    }
+    
+    impl Listener {
+        pub fn incoming(&self) -> impl Iterator<Item = TcpStream> + '_ {
+            self.tcp.incoming().flatten()
+        }
+        
+        /// True if the connection opened with our token, which is then dropped from `lines`
+        pub fn accept(&self, lines: &mut Vec<String>) -> bool {
+            if lines.first().map(|line| line.trim()) != Some(self.token.as_str()) {
+                return false;
+            }
+            lines.remove(0);
+            true
+        }
+    }
+    
+    fn token_path() -> io::Result<PathBuf> {
+        let dir = dirs::data_local_dir()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "No local app data directory"))?
+            .join("NexusAccountManager");
+        fs::create_dir_all(&dir)?;
+        Ok(dir.join("handoff.token"))
+    }
+    
+    pub fn bind() -> io::Result<Listener> {
+        let tcp = TcpListener::bind((Ipv4Addr::LOCALHOST, HANDOFF_PORT))?;
+        let token = Alphanumeric.sample_string(&mut rand::rngs::OsRng, 32);
+        fs::write(token_path()?, &token)?;
+        Ok(Listener { tcp, token })
+    }
+    
+    pub fn connect() -> io::Result<TcpStream> {
+        let token = fs::read_to_string(token_path()?)?;
+        let mut stream = TcpStream::connect_timeout(&(Ipv4Addr::LOCALHOST, HANDOFF_PORT).into(), Duration::from_secs(2))?;
+        writeln!(stream, "{}", token.trim())?;
+        Ok(stream)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_parse_nexus_link() {
+        assert_eq!(
+            NexusLink::parse("nexus://launch?account=261&place=920587237&job=5f1c9e2a-0d5b"),
+            Ok(NexusLink { account: "261".to_string(), place_id: Some(920587237), job_id: Some("5f1c9e2a-0d5b".to_string()) })
+        );
+        assert_eq!(
+            NexusLink::parse("nexus:launch/?account=builderman"),
+            Ok(NexusLink { account: "builderman".to_string(), ..Default::default() })
+        );
+        assert!(NexusLink::parse("nexus://launch?place=1818").is_err());
+        assert!(NexusLink::parse("nexus://launch?account=261&job=abc").is_err());
+        assert!(NexusLink::parse("nexus://delete?account=261").is_err());
+        assert!(NexusLink::parse("roblox://placeId=1818").is_err());
+        
+        let args = ["app", "--preset", "x", "nexus://launch?account=1"].map(String::from);
+        assert_eq!(link_arg(args.into_iter()), Some("nexus://launch?account=1".to_string()));
+    }
+    
+    #[test]
+    fn test_handoff_messages() {
+        let all = handoffs(Some("nexus://launch?account=261".to_string()), Some("Alts".to_string()));
+        assert_eq!(all, vec![Handoff::Link("nexus://launch?account=261".to_string()), Handoff::Preset("Alts".to_string())]);
+        assert_eq!(handoffs(None, None), vec![Handoff::Show]);
+        for handoff in all.into_iter().chain([Handoff::Show]) {
+            assert_eq!(Handoff::parse(&handoff.to_message()), Some(handoff));
+        }
+        assert_eq!(Handoff::parse("GET / HTTP/1.1"), None);
+    }
+}
@@ -236,3 +236,76 @@ pub fn label_badge(ui: &mut egui::Ui, text: &str, color: Color32) {
         ui.label(egui::RichText::new(text).color(color).size(9.5).strong());
     });
 }
+
+/// Line chart over `points` (label, value), filling the available width.
+/// Points listed in `highlights` are marked in red; hovering shows the nearest point.
+pub fn line_chart(ui: &mut egui::Ui, points: &[(String, f64)], highlights: &[usize], height: f32, color: Color32) -> egui::Response {
+    let (rect, response) = ui.allocate_exact_size(Vec2::new(ui.available_width(), height), egui::Sense::hover());
+    if !ui.is_rect_visible(rect) {
+        return response;
+    }
+
+    let painter = ui.painter_at(rect);
+    painter.rect(rect, Rounding::same(6.0), Colors::BG_INPUT, Stroke::new(0.5, Colors::BORDER_SUBTLE));
+
+    if points.len() < 2 {
+        painter.text(
+            rect.center(),
+            egui::Align2::CENTER_CENTER,
+            "Not enough data yet",
+            egui::FontId::proportional(11.0),
+            Colors::TEXT_MUTED,
+        );
+        return response;
+    }
+
+    let plot = rect.shrink2(Vec2::new(10.0, 14.0));
+    let min = points.iter().map(|(_, v)| *v).fold(f64::INFINITY, f64::min);
+    let max = points.iter().map(|(_, v)| *v).fold(f64::NEG_INFINITY, f64::max);
+    let span = if max > min { max - min } else { 1.0 };
+
+    let to_screen = |i: usize, value: f64| {
+        let x = plot.left() + plot.width() * i as f32 / (points.len() - 1) as f32;
+        let y = plot.bottom() - plot.height() * ((value - min) / span) as f32;
+        egui::pos2(x, y)
+    };
+
+    let line: Vec<egui::Pos2> = points.iter().enumerate().map(|(i, (_, v))| to_screen(i, *v)).collect();
+    painter.add(egui::Shape::line(line.clone(), Stroke::new(1.5, color)));
+    for &i in highlights {
+        if let Some(pos) = line.get(i) {
+            painter.circle_filled(*pos, 4.0, Colors::ACCENT_RED);
+        }
+    }
+
+    let font = egui::FontId::proportional(9.5);
+    painter.text(rect.left_top() + Vec2::new(6.0, 2.0), egui::Align2::LEFT_TOP, format_chart_value(max), font.clone(), Colors::TEXT_MUTED);
+    painter.text(rect.left_bottom() + Vec2::new(6.0, -2.0), egui::Align2::LEFT_BOTTOM, format_chart_value(min), font, Colors::TEXT_MUTED);
+
+    if let Some(hover) = response.hover_pos() {
+        let nearest = line.iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| (a.x - hover.x).abs().total_cmp(&(b.x - hover.x).abs()))
+            .map(|(i, _)| i);
+        if let Some(i) = nearest {
+            painter.circle_filled(line[i], 3.5, color);
+            let (label, value) = &points[i];
+            response.clone().on_hover_text(format!("{}\n{}", label, format_chart_value(*value)));
+        }
+    }
+
+    response
+}
+
+/// Thousands-separated integer for chart labels
+pub fn format_chart_value(value: f64) -> String {
+    let raw = (value.round() as i64).abs().to_string();
+    let mut grouped = String::new();
+    for (i, c) in raw.chars().enumerate() {
+        if i > 0 && (raw.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    if value < 0.0 { format!("-{}", grouped) } else { grouped }
+}
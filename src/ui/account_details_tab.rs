@@ -0,0 +1,557 @@
+use eframe::egui::{self, RichText};
+use crate::account::{RobloxAccount, UserPresenceType};
+use crate::theme::{self, Colors};
+use super::{Action, DetailsTab, NexusApp, Tab};
+
+impl NexusApp {
+    pub fn render_account_details_tab(&mut self, ui: &mut egui::Ui) {
+        let Some(idx) = self.selected else {
+            self.tab = Tab::Accounts;
+            return;
+        };
+        let Some(account) = self.data.accounts.get(idx).cloned() else {
+            self.tab = Tab::Accounts;
+            return;
+        };
+        let loading = account.user_id.is_some_and(|id| self.account_fetches.contains_key(&id));
+        
+        ui.horizontal(|ui| {
+            theme::section_header(ui, "📋", "ACCOUNT DETAILS");
+            
+            ui.add_space(16.0);
+            theme::label_badge(ui, &account.username, Colors::ACCENT_BLUE);
+            if let Some(ref display_name) = account.display_name {
+                ui.label(RichText::new(format!("({})", display_name)).color(Colors::TEXT_MUTED).size(11.0));
+            }
+            
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui.add(theme::secondary_button("← Accounts")).clicked() {
+                    self.tab = Tab::Accounts;
+                }
+                
+                ui.add_space(8.0);
+                
+                if loading {
+                    ui.spinner();
+                    ui.label(RichText::new("Fetching...").color(Colors::TEXT_MUTED).size(10.0));
+                } else if ui.add(theme::secondary_button("🔄 Refresh")).clicked() {
+                    self.action = Action::FetchAccountInfo(idx);
+                }
+                
+                if let Some(ref last_fetch) = account.last_info_fetch {
+                    ui.label(RichText::new(format!("Updated: {}", last_fetch)).color(Colors::TEXT_MUTED).size(10.0));
+                }
+            });
+        });
+        
+        ui.add_space(12.0);
+        
+        ui.horizontal(|ui| {
+            for tab in DetailsTab::ALL {
+                if ui.selectable_label(self.details_tab == tab, RichText::new(tab.label()).size(13.0)).clicked() {
+                    self.details_tab = tab;
+                    let friends_loaded = account.user_id.is_some_and(|id| self.friends.contains_key(&id));
+                    let requests_loaded = account.user_id.is_some_and(|id| self.friend_requests.contains_key(&id));
+                    if tab == DetailsTab::Friends && !friends_loaded {
+                        self.action = Action::FetchFriends(idx);
+                    } else if tab == DetailsTab::Requests && !requests_loaded {
+                        self.action = Action::FetchFriendRequests(idx);
+                    }
+                }
+            }
+        });
+        
+        ui.add_space(12.0);
+        
+        egui::ScrollArea::vertical().auto_shrink([false, false]).show(ui, |ui| {
+            match self.details_tab {
+                DetailsTab::Profile => self.render_details_profile(ui, &account),
+                DetailsTab::Inventory => self.render_details_inventory(ui, &account),
+                DetailsTab::Friends => self.render_details_friends(ui, idx, &account),
+                DetailsTab::Requests => self.render_details_requests(ui, idx, &account),
+            }
+        });
+    }
+    
+    fn render_details_profile(&mut self, ui: &mut egui::Ui, account: &RobloxAccount) {
+        let Some(ref details) = account.details else {
+            theme::section_frame().show(ui, |ui| {
+                ui.vertical_centered(|ui| {
+                    ui.add_space(20.0);
+                    if account.user_id.is_some_and(|id| self.account_fetches.contains_key(&id)) {
+                        ui.spinner();
+                        ui.label(RichText::new("Loading profile...").size(16.0).color(Colors::TEXT_MUTED));
+                    } else {
+                        ui.label(RichText::new("No details fetched yet").size(16.0).color(Colors::TEXT_MUTED));
+                        ui.label(RichText::new("Use Refresh to load this account's profile").color(Colors::TEXT_SECONDARY).size(12.0));
+                    }
+                    ui.add_space(20.0);
+                });
+            });
+            return;
+        };
+        
+        ui.horizontal_top(|ui| {
+            theme::section_frame().show(ui, |ui| {
+                ui.set_width(220.0);
+                match details.full_body_url {
+                    Some(ref url) => {
+                        ui.add(egui::Image::from_uri(url)
+                            .fit_to_exact_size(egui::vec2(200.0, 200.0))
+                            .rounding(egui::Rounding::same(8.0)));
+                    }
+                    None => {
+                        ui.allocate_ui(egui::vec2(200.0, 200.0), |ui| {
+                            ui.centered_and_justified(|ui| {
+                                ui.label(RichText::new("No avatar").color(Colors::TEXT_MUTED));
+                            });
+                        });
+                    }
+                }
+            });
+            
+            ui.add_space(12.0);
+            
+            ui.vertical(|ui| {
+                theme::section_frame().show(ui, |ui| {
+                    ui.set_min_width(ui.available_width());
+                    theme::section_header(ui, "👤", "PROFILE");
+                    ui.add_space(8.0);
+                    
+                    ui.horizontal(|ui| {
+                        ui.label(RichText::new(&account.username).color(Colors::TEXT_PRIMARY).size(18.0).strong());
+                        if details.has_verified_badge {
+                            theme::label_badge(ui, "✔ VERIFIED", Colors::ACCENT_BLUE);
+                        }
+                        if account.is_premium == Some(true) {
+                            theme::label_badge(ui, "PREMIUM", Colors::ACCENT_YELLOW);
+                        }
+                    });
+                    
+                    if let Some(user_id) = account.user_id {
+                        ui.label(RichText::new(format!("User ID: {}", user_id)).color(Colors::TEXT_MUTED).size(11.0));
+                    }
+                    
+                    ui.add_space(6.0);
+                    
+                    match (details.created_date(), details.age_days()) {
+                        (Some(created), Some(days)) => {
+                            ui.label(RichText::new(format!(
+                                "Joined {} • {} ({} days)",
+                                created.format("%Y-%m-%d"),
+                                format_account_age(days),
+                                days
+                            )).color(Colors::TEXT_SECONDARY).size(12.0));
+                        }
+                        _ => {
+                            ui.label(RichText::new("Join date unknown").color(Colors::TEXT_MUTED).size(12.0));
+                        }
+                    }
+                    
+                    ui.add_space(8.0);
+                    
+                    ui.horizontal_wrapped(|ui| {
+                        if let Some(robux) = account.robux {
+                            theme::stat_chip(ui, &format!("💰 R${}", robux), Colors::ACCENT_GREEN);
+                        }
+                        if let Some(friends) = account.friends_count {
+                            theme::stat_chip(ui, &format!("👥 {} friends", friends), Colors::ACCENT_BLUE);
+                        }
+                        theme::stat_chip(ui, &format!("⭐ {} followers", details.follower_count), Colors::ACCENT_BLUE);
+                        theme::stat_chip(ui, &format!("➡ {} following", details.following_count), Colors::ACCENT_BLUE);
+                        match details.collectibles_count {
+                            Some(c) => theme::stat_chip(ui, &format!("💎 {} collectibles", c), Colors::ACCENT_PURPLE),
+                            None => theme::stat_chip(ui, "💎 Inventory private", Colors::TEXT_MUTED),
+                        }
+                    });
+                });
+                
+                ui.add_space(12.0);
+                
+                theme::section_frame().show(ui, |ui| {
+                    ui.set_min_width(ui.available_width());
+                    theme::section_header(ui, "💰", "ROBUX HISTORY");
+                    
+                    let drops = account.robux_drops(self.data.robux_drop_alert);
+                    if !drops.is_empty() {
+                        ui.label(RichText::new(format!(
+                            "⚠ {} drop(s) over R${} — check for unauthorized purchases or trades",
+                            drops.len(), self.data.robux_drop_alert
+                        )).color(Colors::ACCENT_RED).size(12.0));
+                        ui.add_space(6.0);
+                    }
+                    
+                    let history: Vec<(String, f64)> = account.robux_history.iter()
+                        .map(|p| (p.time.clone(), p.robux as f64))
+                        .collect();
+                    theme::line_chart(ui, &history, &drops, 110.0, Colors::ACCENT_GREEN);
+                });
+                
+                ui.add_space(12.0);
+                
+                theme::section_frame().show(ui, |ui| {
+                    ui.set_min_width(ui.available_width());
+                    ui.horizontal(|ui| {
+                        theme::section_header(ui, "🏰", "GROUPS");
+                        theme::label_badge(ui, &details.groups.len().to_string(), Colors::ACCENT_BLUE);
+                    });
+                    ui.add_space(8.0);
+                    
+                    if details.groups.is_empty() {
+                        ui.label(RichText::new("Not in any groups").color(Colors::TEXT_MUTED).size(12.0));
+                    } else {
+                        egui::Grid::new("details_groups")
+                            .num_columns(3)
+                            .spacing([16.0, 6.0])
+                            .striped(true)
+                            .show(ui, |ui| {
+                                ui.label(RichText::new("GROUP").color(Colors::TEXT_MUTED).size(10.0).strong());
+                                ui.label(RichText::new("ROLE").color(Colors::TEXT_MUTED).size(10.0).strong());
+                                ui.label(RichText::new("RANK").color(Colors::TEXT_MUTED).size(10.0).strong());
+                                ui.end_row();
+                                
+                                for group in &details.groups {
+                                    ui.hyperlink_to(
+                                        RichText::new(&group.name).color(Colors::TEXT_PRIMARY).size(12.0),
+                                        format!("https://www.roblox.com/groups/{}", group.group_id),
+                                    );
+                                    ui.label(RichText::new(&group.role).color(Colors::TEXT_SECONDARY).size(12.0));
+                                    ui.label(RichText::new(group.rank.to_string()).color(Colors::TEXT_MUTED).size(12.0));
+                                    ui.end_row();
+                                }
+                            });
+                    }
+                });
+            });
+        });
+    }
+    
+    fn render_details_inventory(&mut self, ui: &mut egui::Ui, account: &RobloxAccount) {
+        theme::section_frame().show(ui, |ui| {
+            ui.set_min_width(ui.available_width());
+            theme::section_header(ui, "💎", "LIMITED ITEMS");
+            
+            if account.collectibles_count.is_none() {
+                if account.user_id.is_some_and(|id| self.account_fetches.contains_key(&id)) {
+                    ui.horizontal(|ui| {
+                        ui.spinner();
+                        ui.label(RichText::new("Loading inventory...").color(Colors::TEXT_MUTED).size(12.0));
+                    });
+                } else {
+                    ui.label(RichText::new("Inventory not fetched yet, or private").color(Colors::TEXT_MUTED).size(12.0));
+                }
+                return;
+            }
+            
+            ui.horizontal_wrapped(|ui| {
+                theme::stat_chip(ui, &format!("💎 {} items", account.collectibles.len()), Colors::ACCENT_PURPLE);
+                theme::stat_chip(ui, &format!("📈 {} RAP", theme::format_chart_value(account.inventory_value() as f64)), Colors::ACCENT_GREEN);
+            });
+            ui.add_space(10.0);
+            
+            let history: Vec<(String, f64)> = account.value_history.iter()
+                .map(|s| (s.time.clone(), s.total_rap as f64))
+                .collect();
+            theme::line_chart(ui, &history, &[], 90.0, Colors::ACCENT_GREEN);
+            ui.add_space(10.0);
+            
+            if account.collectibles.is_empty() {
+                ui.label(RichText::new("No limited items").color(Colors::TEXT_MUTED).size(12.0));
+                return;
+            }
+            
+            let mut items = account.collectibles.clone();
+            items.sort_by_key(|item| std::cmp::Reverse(item.rap));
+            
+            egui::Grid::new("details_inventory")
+                .num_columns(3)
+                .spacing([16.0, 6.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.label(RichText::new("ITEM").color(Colors::TEXT_MUTED).size(10.0).strong());
+                    ui.label(RichText::new("SERIAL").color(Colors::TEXT_MUTED).size(10.0).strong());
+                    ui.label(RichText::new("RAP").color(Colors::TEXT_MUTED).size(10.0).strong());
+                    ui.end_row();
+                    
+                    for item in &items {
+                        ui.hyperlink_to(
+                            RichText::new(&item.name).color(Colors::TEXT_PRIMARY).size(12.0),
+                            format!("https://www.roblox.com/catalog/{}", item.asset_id),
+                        );
+                        let serial = item.serial.map(|s| format!("#{}", s)).unwrap_or_else(|| "—".to_string());
+                        ui.label(RichText::new(serial).color(Colors::TEXT_SECONDARY).size(12.0));
+                        ui.label(RichText::new(theme::format_chart_value(item.rap as f64)).color(Colors::ACCENT_GREEN).size(12.0));
+                        ui.end_row();
+                    }
+                });
+        });
+        
+        ui.add_space(12.0);
+        
+        theme::section_frame().show(ui, |ui| {
+            ui.set_min_width(ui.available_width());
+            theme::section_header(ui, "🌐", "ALL ACCOUNTS");
+            
+            let (total_rap, item_count) = self.data.total_inventory_value();
+            ui.horizontal_wrapped(|ui| {
+                theme::stat_chip(ui, &format!("💎 {} items", item_count), Colors::ACCENT_PURPLE);
+                theme::stat_chip(ui, &format!("📈 {} RAP", theme::format_chart_value(total_rap as f64)), Colors::ACCENT_GREEN);
+            });
+            ui.add_space(10.0);
+            
+            let history: Vec<(String, f64)> = self.data.value_history.iter()
+                .map(|s| (s.time.clone(), s.total_rap as f64))
+                .collect();
+            theme::line_chart(ui, &history, &[], 90.0, Colors::ACCENT_BLUE);
+            ui.add_space(10.0);
+            
+            let mut by_value: Vec<(&str, u64, usize)> = self.data.accounts.iter()
+                .filter(|a| !a.collectibles.is_empty())
+                .map(|a| (a.username.as_str(), a.inventory_value(), a.collectibles.len()))
+                .collect();
+            by_value.sort_by_key(|(_, value, _)| std::cmp::Reverse(*value));
+            
+            egui::Grid::new("details_inventory_accounts")
+                .num_columns(3)
+                .spacing([16.0, 6.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.label(RichText::new("ACCOUNT").color(Colors::TEXT_MUTED).size(10.0).strong());
+                    ui.label(RichText::new("ITEMS").color(Colors::TEXT_MUTED).size(10.0).strong());
+                    ui.label(RichText::new("RAP").color(Colors::TEXT_MUTED).size(10.0).strong());
+                    ui.end_row();
+                    
+                    for (username, value, count) in by_value {
+                        ui.label(RichText::new(username).color(Colors::TEXT_PRIMARY).size(12.0));
+                        ui.label(RichText::new(count.to_string()).color(Colors::TEXT_SECONDARY).size(12.0));
+                        ui.label(RichText::new(theme::format_chart_value(value as f64)).color(Colors::ACCENT_GREEN).size(12.0));
+                        ui.end_row();
+                    }
+                });
+        });
+    }
+    
+    fn render_details_friends(&mut self, ui: &mut egui::Ui, idx: usize, account: &RobloxAccount) {
+        theme::section_frame().show(ui, |ui| {
+            ui.set_min_width(ui.available_width());
+            
+            let friends = account.user_id.and_then(|id| self.friends.get(&id)).cloned();
+            
+            ui.horizontal(|ui| {
+                theme::section_header(ui, "👥", "FRIENDS");
+                if let Some(ref friends) = friends {
+                    theme::label_badge(ui, &friends.len().to_string(), Colors::ACCENT_BLUE);
+                }
+                
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.add(theme::secondary_button("🔄 Reload")).clicked() {
+                        self.action = Action::FetchFriends(idx);
+                    }
+                    ui.checkbox(&mut self.friends_online_only, RichText::new("Online only").size(12.0));
+                });
+            });
+            
+            let Some(friends) = friends else {
+                ui.label(RichText::new("Friends not loaded yet").color(Colors::TEXT_MUTED).size(12.0));
+                return;
+            };
+            
+            theme::input_frame().show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new("🔍").size(12.0).color(Colors::TEXT_MUTED));
+                    ui.add(
+                        egui::TextEdit::singleline(&mut self.friends_search)
+                            .desired_width(ui.available_width())
+                            .hint_text(RichText::new("Search friends...").color(Colors::TEXT_MUTED))
+                            .text_color(Colors::TEXT_PRIMARY)
+                            .frame(false)
+                    );
+                });
+            });
+            
+            ui.add_space(8.0);
+            
+            let search = self.friends_search.to_lowercase();
+            let mut shown: Vec<&crate::api::FriendInfo> = friends.iter()
+                .filter(|f| !self.friends_online_only || f.presence.as_ref().is_some_and(|p| p.presence_type != UserPresenceType::Offline))
+                .filter(|f| search.is_empty()
+                    || f.username.to_lowercase().contains(&search)
+                    || f.display_name.to_lowercase().contains(&search))
+                .collect();
+            // In-game first, then online, then offline
+            shown.sort_by_key(|f| {
+                let order = match f.presence.as_ref().map(|p| &p.presence_type) {
+                    Some(UserPresenceType::InGame) => 0,
+                    Some(UserPresenceType::InStudio) => 1,
+                    Some(UserPresenceType::Online) => 2,
+                    _ => 3,
+                };
+                (order, f.username.to_lowercase())
+            });
+            
+            if shown.is_empty() {
+                ui.label(RichText::new("No friends match").color(Colors::TEXT_MUTED).size(12.0));
+                return;
+            }
+            
+            for friend in shown {
+                ui.horizontal(|ui| {
+                    match friend.headshot_url {
+                        Some(ref url) => {
+                            ui.add(egui::Image::from_uri(url)
+                                .fit_to_exact_size(egui::vec2(32.0, 32.0))
+                                .rounding(egui::Rounding::same(6.0)));
+                        }
+                        None => theme::draw_status_circle(ui, Colors::BG_ELEVATED, 32.0),
+                    }
+                    ui.add_space(6.0);
+                    
+                    ui.vertical(|ui| {
+                        ui.horizontal(|ui| {
+                            let name = if friend.display_name.is_empty() { &friend.username } else { &friend.display_name };
+                            ui.label(RichText::new(name).color(Colors::TEXT_PRIMARY).size(12.5).strong());
+                            ui.label(RichText::new(format!("@{}", friend.username)).color(Colors::TEXT_MUTED).size(10.5));
+                        });
+                        
+                        ui.horizontal(|ui| {
+                            let presence_type = friend.presence.as_ref()
+                                .map(|p| p.presence_type.clone())
+                                .unwrap_or_default();
+                            theme::draw_status_circle(ui, presence_type.color(), 6.0);
+                            ui.label(RichText::new(presence_type.label()).color(presence_type.color()).size(10.0));
+                            
+                            let location = friend.presence.as_ref()
+                                .and_then(|p| p.last_location.as_deref())
+                                .filter(|l| !l.is_empty() && presence_type != UserPresenceType::Offline);
+                            if let Some(location) = location {
+                                ui.label(RichText::new("·").color(Colors::TEXT_MUTED).size(10.0));
+                                ui.label(RichText::new(location).color(Colors::ACCENT_BLUE).size(10.0));
+                            }
+                        });
+                    });
+                    
+                    let joinable = friend.presence.as_ref()
+                        .is_some_and(|p| p.presence_type == UserPresenceType::InGame && p.place_id.is_some());
+                    if joinable {
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            if ui.add(theme::success_button("▶ Join")).clicked() {
+                                self.action = Action::JoinFriend(idx, friend.user_id);
+                            }
+                        });
+                    }
+                });
+                ui.add_space(4.0);
+            }
+        });
+    }
+    
+    fn render_details_requests(&mut self, ui: &mut egui::Ui, idx: usize, account: &RobloxAccount) {
+        theme::section_frame().show(ui, |ui| {
+            ui.set_min_width(ui.available_width());
+            
+            let requests = account.user_id.and_then(|id| self.friend_requests.get(&id)).cloned();
+            
+            ui.horizontal(|ui| {
+                theme::section_header(ui, "📨", "FRIEND REQUESTS");
+                if let Some(ref requests) = requests {
+                    theme::label_badge(ui, &requests.len().to_string(), Colors::ACCENT_BLUE);
+                }
+                
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.add(theme::secondary_button("🔄 Reload")).clicked() {
+                        self.action = Action::FetchFriendRequests(idx);
+                    }
+                    let has_requests = requests.as_ref().is_some_and(|r| !r.is_empty());
+                    if has_requests && ui.add(theme::danger_button("Decline All")).clicked() {
+                        self.action = Action::DeclineAllFriendRequests(idx);
+                    }
+                });
+            });
+            
+            let Some(requests) = requests else {
+                ui.label(RichText::new("Requests not loaded yet").color(Colors::TEXT_MUTED).size(12.0));
+                return;
+            };
+            
+            if requests.is_empty() {
+                ui.label(RichText::new("No pending friend requests").color(Colors::TEXT_MUTED).size(12.0));
+                return;
+            }
+            
+            egui::Grid::new("details_friend_requests")
+                .num_columns(5)
+                .spacing([16.0, 8.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.label(RichText::new("USER").color(Colors::TEXT_MUTED).size(10.0).strong());
+                    ui.label(RichText::new("ACCOUNT AGE").color(Colors::TEXT_MUTED).size(10.0).strong());
+                    ui.label(RichText::new("MUTUALS").color(Colors::TEXT_MUTED).size(10.0).strong());
+                    ui.label(RichText::new("SENT").color(Colors::TEXT_MUTED).size(10.0).strong());
+                    ui.label("");
+                    ui.end_row();
+                    
+                    for request in &requests {
+                        ui.vertical(|ui| {
+                            ui.hyperlink_to(
+                                RichText::new(&request.username).color(Colors::TEXT_PRIMARY).size(12.0),
+                                format!("https://www.roblox.com/users/{}/profile", request.user_id),
+                            );
+                            if !request.display_name.is_empty() && request.display_name != request.username {
+                                ui.label(RichText::new(&request.display_name).color(Colors::TEXT_MUTED).size(10.0));
+                            }
+                        });
+                        
+                        // Brand-new accounts are the usual spam senders
+                        match days_since(request.created.as_deref()) {
+                            Some(days) => {
+                                let color = if days < 30 { Colors::ACCENT_YELLOW } else { Colors::TEXT_SECONDARY };
+                                ui.label(RichText::new(format_account_age(days)).color(color).size(12.0));
+                            }
+                            None => {
+                                ui.label(RichText::new("—").color(Colors::TEXT_MUTED).size(12.0));
+                            }
+                        }
+                        
+                        let mutuals = ui.label(RichText::new(request.mutual_friends.len().to_string()).color(Colors::TEXT_SECONDARY).size(12.0));
+                        if !request.mutual_friends.is_empty() {
+                            mutuals.on_hover_text(request.mutual_friends.join(", "));
+                        }
+                        
+                        let sent = request.sent_at.as_deref()
+                            .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+                            .map(|t| t.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string())
+                            .unwrap_or_else(|| "—".to_string());
+                        ui.label(RichText::new(sent).color(Colors::TEXT_MUTED).size(11.0));
+                        
+                        ui.horizontal(|ui| {
+                            if ui.add(theme::success_button("✔ Accept")).clicked() {
+                                self.action = Action::AcceptFriendRequest(idx, request.user_id);
+                            }
+                            if ui.add(theme::secondary_button("✖ Decline")).clicked() {
+                                self.action = Action::DeclineFriendRequest(idx, request.user_id);
+                            }
+                        });
+                        ui.end_row();
+                    }
+                });
+        });
+    }
+}
+
+/// Whole days since an ISO 8601 timestamp
+fn days_since(timestamp: Option<&str>) -> Option<i64> {
+    let time = chrono::DateTime::parse_from_rfc3339(timestamp?).ok()?;
+    Some((chrono::Local::now().date_naive() - time.date_naive()).num_days())
+}
+
+/// "3 years, 2 months" style age from a day count
+fn format_account_age(days: i64) -> String {
+    let years = days / 365;
+    let months = (days % 365) / 30;
+    match (years, months) {
+        (0, 0) => "less than a month".to_string(),
+        (0, m) => format!("{} month{}", m, if m == 1 { "" } else { "s" }),
+        (y, 0) => format!("{} year{}", y, if y == 1 { "" } else { "s" }),
+        (y, m) => format!("{} year{}, {} month{}", y, if y == 1 { "" } else { "s" }, m, if m == 1 { "" } else { "s" }),
+    }
+}
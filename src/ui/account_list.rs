@@ -24,6 +24,12 @@ impl NexusApp {
                         &format!("{}", self.data.accounts.len()),
                         Colors::ACCENT_BLUE,
                     );
+                    if self.data.accounts.iter().any(|a| a.robux.is_some()) {
+                        theme::label_badge(ui,
+                            &format!("R${} TOTAL", theme::format_chart_value(self.data.total_robux() as f64)),
+                            Colors::ACCENT_GREEN,
+                        );
+                    }
                     
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                         if !self.batch_selected.is_empty() {
@@ -33,6 +39,36 @@ impl NexusApp {
                                 self.action = Action::BatchLaunch;
                             }
                             ui.add_space(4.0);
+                            if ui.add(theme::icon_button("👥"))
+                                .on_hover_text("Launch the checked accounts into the same server")
+                                .clicked() {
+                                self.action = Action::BatchLaunchTogether;
+                            }
+                            ui.add_space(4.0);
+                        }
+                        
+                        if ui.add(theme::icon_button("💾"))
+                            .on_hover_text("Save checked accounts and the current game as a launch preset")
+                            .clicked() {
+                            self.action = Action::EditPreset(None);
+                        }
+                        
+                        let running = self.client_tracker.running_count();
+                        if running > 0 {
+                            if ui.add(theme::danger_button(
+                                &format!("⏹ Close {}", running)
+                            ).min_size(egui::vec2(0.0, 24.0)))
+                                .on_hover_text("Close every client launched from here")
+                                .clicked() {
+                                self.action = Action::CloseAllClients;
+                            }
+                            ui.add_space(4.0);
+                        }
+                        
+                        if ui.add(theme::icon_button("📊"))
+                            .on_hover_text("Fetch info for all accounts")
+                            .clicked() {
+                            self.action = Action::FetchAllAccountInfo;
                         }
                         
                         if ui.add(theme::icon_button("🖼"))
@@ -56,6 +92,32 @@ impl NexusApp {
                     });
                 });
                 
+                if !self.data.launch_presets.is_empty() {
+                    ui.add_space(6.0);
+                    ui.horizontal_wrapped(|ui| {
+                        ui.label(RichText::new("PRESETS").size(11.0).color(Colors::TEXT_MUTED).strong());
+                        ui.add_space(4.0);
+                        for (idx, preset) in self.data.launch_presets.iter().enumerate() {
+                            let target = if preset.place_id.trim().is_empty() { "app".to_string() } else { preset.place_id.trim().to_string() };
+                            let response = ui.add(theme::secondary_button(&format!("▶ {}", preset.name)))
+                                .on_hover_text(format!("{} account(s) → {}", preset.user_ids.len(), target));
+                            if response.clicked() {
+                                self.action = Action::LaunchPreset(idx);
+                            }
+                            response.context_menu(|ui| {
+                                if ui.button("✏ Edit").clicked() {
+                                    self.action = Action::EditPreset(Some(idx));
+                                    ui.close_menu();
+                                }
+                                if ui.button("🗑 Delete").clicked() {
+                                    self.action = Action::DeletePreset(idx);
+                                    ui.close_menu();
+                                }
+                            });
+                        }
+                    });
+                }
+                
                 ui.add_space(6.0);
                 
                 theme::input_frame().show(ui, |ui| {
@@ -221,6 +283,15 @@ impl NexusApp {
                                                     if is_premium == Some(true) {
                                                         ui.label(RichText::new("⭐").size(10.0));
                                                     }
+                                                    if let Some(pending) = self.data.accounts[idx].pending_friend_requests.filter(|&n| n > 0) {
+                                                        theme::label_badge(ui, &format!("📨 {}", pending), Colors::ACCENT_BLUE);
+                                                    }
+                                                    if let Some(lost) = self.data.accounts[idx].latest_robux_drop(self.data.robux_drop_alert) {
+                                                        theme::label_badge(ui, &format!("⚠ -R${}", lost), Colors::ACCENT_RED);
+                                                    }
+                                                    if self.client_tracker.client_for(&username).is_some() {
+                                                        theme::label_badge(ui, "▶ RUNNING", Colors::ACCENT_GREEN);
+                                                    }
                                                 });
                                                 
                                                 if let Some(dn) = &display_name {
@@ -253,6 +324,22 @@ impl NexusApp {
                                                     }
                                                 });
                                             });
+                                            
+                                            let account = &self.data.accounts[idx];
+                                            let can_launch = status == AccountStatus::Valid && account.cookie.is_some();
+                                            let overrides = account.launch_defaults.is_set().then(|| account.launch_defaults.summary());
+                                            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                                let hover = match overrides {
+                                                    Some(ref summary) => format!("Launch with this account's defaults: {}", summary),
+                                                    None => "Launch into the selected game".to_string(),
+                                                };
+                                                if ui.add_enabled(can_launch, theme::icon_button("▶")).on_hover_text(hover).clicked() {
+                                                    self.action = Action::LaunchAccount(idx);
+                                                }
+                                                if overrides.is_some() {
+                                                    theme::label_badge(ui, "⚙ CUSTOM", Colors::ACCENT_PURPLE);
+                                                }
+                                            });
                                         });
                                     });
                                 
@@ -343,6 +430,11 @@ impl NexusApp {
                                     ).fill(Colors::BG_LIGHT).rounding(egui::Rounding::same(4.0))).clicked() {
                                         self.action = Action::FetchAccountInfo(idx);
                                     }
+                                    if ui.add(egui::Button::new(
+                                        RichText::new("📋 Details").color(Colors::TEXT_SECONDARY).size(11.0)
+                                    ).fill(Colors::BG_LIGHT).rounding(egui::Rounding::same(4.0))).clicked() {
+                                        self.action = Action::OpenAccountDetails(idx);
+                                    }
                                     if let Some(last_fetch) = &account.last_info_fetch {
                                         ui.label(RichText::new(format!("Updated: {}", last_fetch)).color(Colors::TEXT_MUTED).size(9.0));
                                     }
@@ -370,16 +462,91 @@ impl NexusApp {
                                 ui.horizontal(|ui| {
                                     ui.label(RichText::new("Place ID:").color(Colors::TEXT_SECONDARY).size(12.0));
                                     theme::input_frame().show(ui, |ui| {
-                                        ui.add(egui::TextEdit::singleline(&mut self.place_id)
+                                        let response = ui.add(egui::TextEdit::singleline(&mut self.place_id)
                                             .desired_width(120.0)
-                                            .hint_text(RichText::new("Enter ID").color(Colors::TEXT_MUTED))
+                                            .hint_text(RichText::new("ID or link").color(Colors::TEXT_MUTED))
                                             .text_color(Colors::TEXT_PRIMARY)
-                                            .frame(false));
+                                            .frame(false))
+                                            .on_hover_text("Place ID, or paste any Roblox game, server, share or profile link");
+                                        // Typing drops an earlier link and a paste previews what it points at.
+                                        // The box itself is only rewritten once editing is done.
+                                        if response.changed() {
+                                            let pasted = ui.input(|i| i.events.iter().any(|e| matches!(e, egui::Event::Paste(_))));
+                                            self.link_target = if pasted { crate::api::resolve_link(&self.place_id) } else { None };
+                                        }
+                                        if response.lost_focus() {
+                                            if let Some(target) = crate::api::resolve_link(&self.place_id) {
+                                                self.apply_link_target(target);
+                                            }
+                                        }
                                     });
                                     if ui.add(theme::secondary_button("Browse")).clicked() {
                                         self.tab = Tab::Games;
                                     }
                                 });
+                                
+                                if let Some(description) = self.link_target.as_ref().map(|t| t.describe()) {
+                                    ui.add_space(4.0);
+                                    ui.horizontal(|ui| {
+                                        theme::label_badge(ui, "🔗 LINK", Colors::ACCENT_BLUE);
+                                        ui.label(RichText::new(description).color(Colors::TEXT_SECONDARY).size(11.0));
+                                        if ui.add(theme::icon_button("✕")).on_hover_text("Clear link").clicked() {
+                                            self.link_target = None;
+                                        }
+                                    });
+                                }
+                                
+                                if account.launch_defaults.is_set() {
+                                    ui.add_space(6.0);
+                                    ui.horizontal(|ui| {
+                                        theme::label_badge(ui, "⚙ CUSTOM", Colors::ACCENT_PURPLE);
+                                        ui.label(RichText::new(format!("This account {}", account.launch_defaults.summary()))
+                                            .color(Colors::TEXT_SECONDARY).size(10.0));
+                                    });
+                                }
+                                
+                                ui.add_space(6.0);
+                                egui::CollapsingHeader::new(RichText::new("Launch defaults").size(11.0).color(Colors::TEXT_SECONDARY))
+                                    .id_salt(("launch_defaults", idx))
+                                    .show(ui, |ui| {
+                                        let mut defaults = account.launch_defaults.clone();
+                                        let mut changed = false;
+                                        
+                                        egui::Grid::new(("launch_defaults_grid", idx)).num_columns(2).spacing([8.0, 6.0]).show(ui, |ui| {
+                                            ui.label(RichText::new("Place ID").color(Colors::TEXT_SECONDARY).size(11.0));
+                                            changed |= ui.add(egui::TextEdit::singleline(&mut defaults.place_id)
+                                                .desired_width(160.0)
+                                                .hint_text("Selected game")).changed();
+                                            ui.end_row();
+                                            
+                                            ui.label(RichText::new("Private server").color(Colors::TEXT_SECONDARY).size(11.0));
+                                            changed |= ui.add(egui::TextEdit::singleline(&mut defaults.private_server_link)
+                                                .desired_width(160.0)
+                                                .hint_text("Share link")).changed();
+                                            ui.end_row();
+                                            
+                                            ui.label(RichText::new("Locale").color(Colors::TEXT_SECONDARY).size(11.0));
+                                            changed |= ui.add(egui::TextEdit::singleline(&mut defaults.locale)
+                                                .desired_width(160.0)
+                                                .hint_text(self.data.launch_locale.as_str())).changed();
+                                            ui.end_row();
+                                        });
+                                        
+                                        let last = account.last_server.as_ref()
+                                            .map(|s| format!("Last seen in place {}", s.place_id))
+                                            .unwrap_or_else(|| "No server seen yet".to_string());
+                                        changed |= ui.checkbox(&mut defaults.rejoin_last_server,
+                                            RichText::new("Launch into last server").color(Colors::TEXT_SECONDARY).size(11.0))
+                                            .on_hover_text(last)
+                                            .changed();
+                                        
+                                        if changed {
+                                            if let Some(account) = self.data.accounts.get_mut(idx) {
+                                                account.launch_defaults = defaults;
+                                                self.data.save();
+                                            }
+                                        }
+                                    });
                             });
                             
                             ui.add_space(10.0);
@@ -412,6 +579,25 @@ impl NexusApp {
                                     ui.label(RichText::new("Verify account to enable launch").color(Colors::TEXT_MUTED).size(10.0));
                                 }
                                 
+                                if let Some(client) = self.client_tracker.client_for(&account.username) {
+                                    ui.add_space(6.0);
+                                    ui.horizontal(|ui| {
+                                        theme::draw_status_circle(ui, Colors::ACCENT_GREEN, 6.0);
+                                        let place = client.place_id
+                                            .map(|id| format!(" · place {}", id))
+                                            .unwrap_or_default();
+                                        ui.label(RichText::new(format!("Running since {} · PID {}{}", client.started.format("%H:%M"), client.pid, place))
+                                            .color(Colors::TEXT_SECONDARY).size(10.0))
+                                            .on_hover_text(client.job_id.as_deref().map(|j| format!("Server {}", j)).unwrap_or_else(|| "Server unknown".to_string()));
+                                        
+                                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                            if ui.add(theme::danger_button("✖ Close Client")).clicked() {
+                                                self.action = Action::CloseClient(idx);
+                                            }
+                                        });
+                                    });
+                                }
+                                
                                 ui.add_space(8.0);
                                 
                                 if ui.add_sized([ui.available_width(), 32.0], theme::secondary_button("🔄  Verify Account")).clicked() {
@@ -440,6 +626,26 @@ impl NexusApp {
                                             self.follow_user_show = true;
                                             self.follow_user_target.clear();
                                         }
+                                        
+                                        let browser_open = account.user_id
+                                            .is_some_and(|id| self.account_browsers.contains_key(&id));
+                                        let (browser_text, browser_hover) = if browser_open {
+                                            ("✖ Close Browser", "Close the browser signed in as this account")
+                                        } else {
+                                            ("🌐 Browser", "Open roblox.com signed in as this account")
+                                        };
+                                        if ui.add(egui::Button::new(
+                                            RichText::new(browser_text).color(Colors::TEXT_SECONDARY).size(11.0)
+                                        ).fill(Colors::BG_LIGHT).stroke(egui::Stroke::new(0.5, Colors::BORDER_DARK))
+                                            .rounding(egui::Rounding::same(5.0)))
+                                        .on_hover_text(browser_hover)
+                                        .clicked() {
+                                            self.action = if browser_open {
+                                                Action::CloseAccountBrowser(idx)
+                                            } else {
+                                                Action::OpenAccountBrowser(idx)
+                                            };
+                                        }
                                     });
                                 }
                                 
@@ -449,6 +655,25 @@ impl NexusApp {
                                         self.tab = Tab::ImportCookie;
                                         self.import_cookie.clear();
                                     }
+                                    
+                                    ui.add_space(6.0);
+                                    let relogin_busy = self.browser_login_session.is_some();
+                                    ui.add_enabled_ui(!relogin_busy, |ui| {
+                                        if ui.add_sized([ui.available_width(), 32.0], theme::secondary_button("🔑 Re-login"))
+                                            .on_hover_text("Open the browser login with this account's username and password filled in")
+                                            .clicked() {
+                                            self.action = Action::ReloginAccount(idx);
+                                        }
+                                    });
+                                    
+                                    if self.relogin_idx() == Some(idx) {
+                                        ui.horizontal(|ui| {
+                                            ui.spinner();
+                                            ui.label(RichText::new("Waiting for login in browser...").color(Colors::TEXT_MUTED).size(10.0));
+                                        });
+                                    } else if account.password.is_empty() {
+                                        ui.label(RichText::new("No stored password - only the username will be filled in").color(Colors::TEXT_MUTED).size(10.0));
+                                    }
                                 }
                             });
                             
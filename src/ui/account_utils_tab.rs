@@ -70,6 +70,44 @@ impl NexusApp {
                     theme::section_header(ui, "🔑", "PASSWORD");
                     ui.add_space(12.0);
                     
+                    let (stored_password, pending_password) = self.data.accounts.get(idx)
+                        .map(|a| (!a.password.is_empty(), a.pending_password.clone()))
+                        .unwrap_or_default();
+                    
+                    if let Some(pending) = pending_password {
+                        ui.label(RichText::new(
+                            "⚠ A password change was interrupted before Roblox answered. It may have gone through."
+                        ).size(12.0).color(Colors::ACCENT_YELLOW));
+                        ui.horizontal(|ui| {
+                            if ui.add(theme::secondary_button("📋 Copy New Password")).clicked() {
+                                ui.output_mut(|o| o.copied_text = pending);
+                            }
+                            if ui.add(theme::success_button("Use New Password")).clicked() {
+                                self.action = Action::ResolvePendingPassword(idx, true);
+                            }
+                            if ui.add(theme::secondary_button("Keep Old Password")).clicked() {
+                                self.action = Action::ResolvePendingPassword(idx, false);
+                            }
+                        });
+                        ui.add_space(8.0);
+                    }
+                    
+                    if !stored_password {
+                        ui.horizontal(|ui| {
+                            ui.label(RichText::new("Current Password:").color(Colors::TEXT_SECONDARY));
+                            
+                            theme::input_frame().show(ui, |ui| {
+                                    ui.add(egui::TextEdit::singleline(&mut self.util_current_password)
+                                        .password(true)
+                                        .desired_width(200.0)
+                                        .hint_text(RichText::new("Not stored for this account").color(Colors::TEXT_MUTED))
+                                        .text_color(Colors::TEXT_PRIMARY)
+                                        .frame(false));
+                                });
+                        });
+                        ui.add_space(4.0);
+                    }
+                    
                     ui.horizontal(|ui| {
                         ui.label(RichText::new("New Password:").color(Colors::TEXT_SECONDARY));
                         
@@ -91,7 +129,37 @@ impl NexusApp {
                     });
                     
                     ui.add_space(4.0);
-                    ui.label(RichText::new("⚠ Make sure to save your new password!").size(11.0).color(Colors::ACCENT_YELLOW));
+                    ui.label(RichText::new("The new password is stored with the account once Roblox confirms the change").size(11.0).color(Colors::TEXT_MUTED));
+                    
+                    ui.add_space(10.0);
+                    ui.horizontal(|ui| {
+                        let selected = self.batch_selected.len();
+                        let button = egui::Button::new(format!("🔄 Rotate Passwords for {} Selected", selected))
+                            .fill(Colors::ACCENT_RED.linear_multiply(0.8))
+                            .min_size(egui::vec2(220.0, 28.0));
+                        if ui.add_enabled(selected > 0, button).clicked() {
+                            self.action = Action::RotateSelectedPasswords;
+                        }
+                        ui.label(RichText::new("Generates a strong random password for each ticked account").color(Colors::TEXT_MUTED).size(11.0));
+                    });
+                    
+                    if !self.password_rotation_report.is_empty() {
+                        ui.add_space(8.0);
+                        egui::Grid::new("password_rotation_report")
+                            .num_columns(2)
+                            .spacing([16.0, 4.0])
+                            .striped(true)
+                            .show(ui, |ui| {
+                                for (username, error) in &self.password_rotation_report {
+                                    ui.label(RichText::new(username).color(Colors::TEXT_PRIMARY).size(12.0));
+                                    match error {
+                                        None => ui.label(RichText::new("✔ Rotated").color(Colors::ACCENT_GREEN).size(12.0)),
+                                        Some(e) => ui.label(RichText::new(format!("✖ {}", e)).color(Colors::ACCENT_RED).size(12.0)),
+                                    };
+                                    ui.end_row();
+                                }
+                            });
+                    }
                 });
             
             ui.add_space(12.0);
@@ -169,6 +237,14 @@ impl NexusApp {
             
             ui.add_space(12.0);
             
+            self.render_blocked_users(ui, idx);
+            
+            ui.add_space(12.0);
+            
+            self.render_privacy_settings(ui, idx);
+            
+            ui.add_space(12.0);
+            
             theme::section_frame().show(ui, |ui| {
                     theme::section_header(ui, "📁", "ORGANIZATION");
                     ui.add_space(12.0);
@@ -321,6 +397,184 @@ impl NexusApp {
         });
     }
     
+    fn render_blocked_users(&mut self, ui: &mut egui::Ui, idx: usize) {
+        let user_id = self.data.accounts.get(idx).and_then(|a| a.user_id);
+        let blocked = user_id.and_then(|id| self.blocked_users.get(&id)).cloned();
+        
+        theme::section_frame().show(ui, |ui| {
+                ui.set_min_width(ui.available_width());
+                ui.horizontal(|ui| {
+                    theme::section_header(ui, "🚫", "BLOCKED USERS");
+                    if let Some(ref blocked) = blocked {
+                        theme::label_badge(ui, &blocked.len().to_string(), Colors::ACCENT_RED);
+                    }
+                    
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        let label = if blocked.is_some() { "🔄 Reload" } else { "Load" };
+                        if ui.add(theme::secondary_button(label)).clicked() {
+                            self.action = Action::FetchBlockedUsers(idx);
+                        }
+                    });
+                });
+                
+                let Some(blocked) = blocked else {
+                    ui.label(RichText::new("Load to see who this account has blocked").color(Colors::TEXT_MUTED).size(12.0));
+                    return;
+                };
+                
+                if blocked.is_empty() {
+                    ui.label(RichText::new("No blocked users").color(Colors::TEXT_MUTED).size(12.0));
+                } else {
+                    for user in &blocked {
+                        ui.horizontal(|ui| {
+                            match user.avatar_url {
+                                Some(ref url) => {
+                                    ui.add(egui::Image::from_uri(url)
+                                        .fit_to_exact_size(egui::vec2(28.0, 28.0))
+                                        .rounding(egui::Rounding::same(5.0)));
+                                }
+                                None => theme::draw_status_circle(ui, Colors::BG_ELEVATED, 28.0),
+                            }
+                            ui.add_space(6.0);
+                            
+                            let name = if user.display_name.is_empty() { &user.username } else { &user.display_name };
+                            ui.label(RichText::new(name).color(Colors::TEXT_PRIMARY).size(12.5));
+                            ui.label(RichText::new(format!("@{}", user.username)).color(Colors::TEXT_MUTED).size(10.5));
+                            
+                            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                if ui.add(theme::secondary_button("Unblock")).clicked() {
+                                    self.action = Action::UnblockUser(idx, user.user_id);
+                                }
+                            });
+                        });
+                    }
+                }
+                
+                ui.add_space(10.0);
+                
+                // Copy to another managed account
+                let targets: Vec<(usize, String)> = self.data.accounts.iter().enumerate()
+                    .filter(|(i, a)| *i != idx && a.cookie.is_some())
+                    .map(|(i, a)| (i, a.username.clone()))
+                    .collect();
+                if targets.is_empty() || blocked.is_empty() {
+                    return;
+                }
+                if self.block_copy_target.is_some_and(|t| !targets.iter().any(|(i, _)| *i == t)) {
+                    self.block_copy_target = None;
+                }
+                
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new("Copy this list to:").color(Colors::TEXT_SECONDARY));
+                    
+                    let selected_text = self.block_copy_target
+                        .and_then(|t| targets.iter().find(|(i, _)| *i == t))
+                        .map(|(_, name)| name.clone())
+                        .unwrap_or_else(|| "Choose account".to_string());
+                    egui::ComboBox::from_id_salt("block_copy_target")
+                        .selected_text(selected_text)
+                        .show_ui(ui, |ui| {
+                            for (i, name) in &targets {
+                                ui.selectable_value(&mut self.block_copy_target, Some(*i), name);
+                            }
+                        });
+                    
+                    if let Some(target) = self.block_copy_target {
+                        if ui.add_enabled(self.block_copy.is_none(), egui::Button::new("Copy Blocks")
+                            .fill(Colors::ACCENT_BLUE)
+                            .min_size(egui::vec2(100.0, 28.0))).clicked()
+                        {
+                            self.action = Action::CopyBlockList(idx, target);
+                        }
+                    }
+                });
+            });
+    }
+    
+    fn render_privacy_settings(&mut self, ui: &mut egui::Ui, idx: usize) {
+        let user_id = self.data.accounts.get(idx).and_then(|a| a.user_id);
+        // The draft follows the selected account
+        if self.privacy_draft_account != user_id {
+            self.privacy_draft = user_id
+                .and_then(|id| self.privacy_settings.get(&id))
+                .cloned()
+                .unwrap_or_default();
+            self.privacy_draft_account = user_id;
+        }
+        let loaded = user_id.and_then(|id| self.privacy_settings.get(&id)).cloned();
+        
+        theme::section_frame().show(ui, |ui| {
+                ui.set_min_width(ui.available_width());
+                ui.horizontal(|ui| {
+                    theme::section_header(ui, "🔒", "PRIVACY");
+                    
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        let label = if loaded.is_some() { "🔄 Reload" } else { "Load" };
+                        if ui.add(theme::secondary_button(label)).clicked() {
+                            self.action = Action::FetchPrivacySettings(idx);
+                        }
+                    });
+                });
+                
+                let Some(loaded) = loaded else {
+                    ui.label(RichText::new("Load to view and change this account's privacy settings").color(Colors::TEXT_MUTED).size(12.0));
+                    return;
+                };
+                
+                egui::Grid::new("privacy_settings")
+                    .num_columns(2)
+                    .spacing([16.0, 8.0])
+                    .show(ui, |ui| {
+                        for setting in crate::api::PRIVACY_SETTINGS {
+                            ui.label(RichText::new(setting.label).color(Colors::TEXT_SECONDARY));
+                            
+                            let Some(value) = self.privacy_draft.get_mut(setting.field) else {
+                                ui.label(RichText::new("Unavailable").color(Colors::TEXT_MUTED).size(12.0));
+                                ui.end_row();
+                                continue;
+                            };
+                            
+                            let modified = loaded.get(setting.field) != Some(&*value);
+                            egui::ComboBox::from_id_salt(setting.field)
+                                .width(220.0)
+                                .selected_text(setting.option_label(value))
+                                .show_ui(ui, |ui| {
+                                    for (option, label) in setting.options {
+                                        ui.selectable_value(value, option.to_string(), *label);
+                                    }
+                                });
+                            if modified {
+                                ui.label(RichText::new("●").color(Colors::ACCENT_YELLOW).size(10.0));
+                            }
+                            ui.end_row();
+                        }
+                    });
+                
+                ui.add_space(10.0);
+                
+                ui.horizontal(|ui| {
+                    if ui.add(egui::Button::new("Apply")
+                        .fill(Colors::ACCENT_BLUE)
+                        .min_size(egui::vec2(80.0, 28.0))).clicked()
+                    {
+                        self.action = Action::ApplyPrivacySettings(idx);
+                    }
+                    
+                    let selected = self.batch_selected.len();
+                    if selected > 0 {
+                        if ui.add(egui::Button::new(format!("Apply to {} Selected", selected))
+                            .fill(Colors::ACCENT_BLUE.linear_multiply(0.7))
+                            .min_size(egui::vec2(140.0, 28.0))).clicked()
+                        {
+                            self.action = Action::ApplyPrivacyToSelected;
+                        }
+                    } else {
+                        ui.label(RichText::new("Tick accounts in the list to apply these settings to several at once").color(Colors::TEXT_MUTED).size(11.0));
+                    }
+                });
+            });
+    }
+    
     fn do_user_action(&mut self, account_idx: usize, action_type: &str) {
         let target = self.util_target_user.trim().to_string();
         
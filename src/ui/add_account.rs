@@ -1,4 +1,5 @@
 use eframe::egui::{self, RichText, Color32};
+use crate::auth::CookieCheck;
 use crate::theme::Colors;
 use super::{Action, NexusApp};
 
@@ -120,13 +121,26 @@ impl NexusApp {
                                         ui.spinner();
                                         ui.add_space(12.0);
                                         ui.vertical(|ui| {
-                                            ui.label(RichText::new("Waiting for login...")
+                                            let status = self.browser_login_status.clone()
+                                                .unwrap_or(crate::auth::BrowserStatus::Launching);
+                                            let waiting_text = match self.relogin_idx().and_then(|idx| self.data.accounts.get(idx)) {
+                                                Some(account) if status == crate::auth::BrowserStatus::WaitingForLogin => {
+                                                    format!("Waiting for re-login of {}...", account.username)
+                                                }
+                                                _ => status.label().to_string(),
+                                            };
+                                            ui.label(RichText::new(waiting_text)
                                                 .color(Colors::ACCENT_BLUE)
                                                 .size(14.0)
                                                 .strong());
                                             ui.label(RichText::new("Complete the login in the browser window")
                                                 .color(Colors::TEXT_MUTED)
                                                 .size(12.0));
+                                            if self.login_queue_enabled && self.relogin_account.is_none() {
+                                                ui.label(RichText::new(format!("Login queue: {} added so far", self.login_queue_added))
+                                                    .color(Colors::TEXT_MUTED)
+                                                    .size(12.0));
+                                            }
                                         });
                                     });
                                 });
@@ -134,8 +148,13 @@ impl NexusApp {
                             ui.add_space(12.0);
                             
                             ui.horizontal(|ui| {
+                                let cancel_text = if self.login_queue_enabled && self.relogin_account.is_none() {
+                                    "Stop Queue"
+                                } else {
+                                    "Cancel"
+                                };
                                 let cancel_btn = egui::Button::new(
-                                    RichText::new("Cancel").color(Colors::TEXT_SECONDARY).size(13.0)
+                                    RichText::new(cancel_text).color(Colors::TEXT_SECONDARY).size(13.0)
                                 )
                                 .fill(Colors::BG_LIGHT)
                                 .stroke(egui::Stroke::new(1.0, Colors::BORDER_DARK))
@@ -146,7 +165,15 @@ impl NexusApp {
                                         session.cancel();
                                     }
                                     self.browser_login_session = None;
-                                    self.set_status("Browser login cancelled", false);
+                                    self.browser_login_status = None;
+                                    self.relogin_account = None;
+                                    
+                                    if self.login_queue_enabled && self.login_queue_added > 0 {
+                                        self.set_status(format!("Login queue stopped - {} account(s) added", self.login_queue_added), false);
+                                    } else {
+                                        self.set_status("Browser login cancelled", false);
+                                    }
+                                    self.login_queue_added = 0;
                                 }
                             });
                         } else {
@@ -160,8 +187,15 @@ impl NexusApp {
                             .rounding(egui::Rounding::same(8.0));
                             
                             if ui.add_sized([ui.available_width(), 44.0], btn).clicked() {
+                                self.login_queue_added = 0;
                                 self.action = Action::StartBrowserLogin;
                             }
+                            
+                            ui.add_space(8.0);
+                            ui.checkbox(&mut self.login_queue_enabled, RichText::new("Keep adding accounts")
+                                .color(Colors::TEXT_SECONDARY)
+                                .size(12.0))
+                                .on_hover_text("Open a fresh login page after each account is added, until you stop the queue");
                         }
                     });
             }
@@ -227,7 +261,10 @@ impl NexusApp {
                         .stroke(egui::Stroke::new(1.0, Colors::BORDER_DARK))
                         .rounding(egui::Rounding::same(6.0));
                         
-                        if ui.add_sized([ui.available_width(), 36.0], find_btn).clicked() {
+                        let width = ui.available_width();
+                        if ui.add_enabled_ui(self.cookie_scan.is_none(), |ui| {
+                            ui.add_sized([width, 36.0], find_btn)
+                        }).inner.clicked() {
                             self.action = Action::FindCookies;
                         }
                         
@@ -242,12 +279,12 @@ impl NexusApp {
                             ui.add_space(8.0);
                             
                             let cookies: Vec<_> = self.found_cookies.iter()
-                                .map(|c| (c.cookie.clone(), c.source.clone()))
+                                .map(|c| (c.cookie.clone(), c.browser.clone(), c.source(), c.username.clone(), c.status_label().to_string(), c.check == CookieCheck::Expired))
                                 .collect();
                             
                             let mut cookie_to_use: Option<String> = None;
                             
-                            for (cookie, source) in &cookies {
+                            for (cookie, browser, source, username, status, expired) in &cookies {
                                 egui::Frame::none()
                                     .fill(Colors::BG_DARK)
                                     .stroke(egui::Stroke::new(1.0, Colors::BORDER_DARK))
@@ -255,12 +292,10 @@ impl NexusApp {
                                     .inner_margin(egui::Margin::same(10.0))
                                     .show(ui, |ui| {
                                         ui.horizontal(|ui| {
-                                            let source_icon = match source.as_str() {
+                                            let source_icon = match browser.as_str() {
                                                 "Windows Registry" => "🪟",
-                                                "Google Chrome" => "🌐",
-                                                "Microsoft Edge" => "🌐",
                                                 "Mozilla Firefox" => "🦊",
-                                                _ => "🍪",
+                                                _ => "🌐",
                                             };
                                             ui.label(RichText::new(source_icon).size(14.0));
                                             ui.add_space(4.0);
@@ -268,6 +303,13 @@ impl NexusApp {
                                                 .color(Colors::ACCENT_BLUE)
                                                 .size(12.0)
                                                 .strong());
+                                            
+                                            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                                match username {
+                                                    Some(name) => ui.label(RichText::new(name).color(Colors::TEXT_PRIMARY).size(12.0)),
+                                                    None => ui.label(RichText::new(status).color(if *expired { Colors::ACCENT_RED } else { Colors::TEXT_MUTED }).size(11.0)),
+                                                };
+                                            });
                                         });
                                         
                                         ui.add_space(6.0);
@@ -348,7 +390,7 @@ impl NexusApp {
                                 ui.add_sized(
                                     [ui.available_width(), 50.0],
                                     egui::TextEdit::multiline(&mut self.import_cookie)
-                                        .hint_text("Paste your .ROBLOSECURITY cookie here...")
+                                        .hint_text("Paste a .ROBLOSECURITY cookie, Cookie header, cookies.txt or JSON export...")
                                         .text_color(Colors::TEXT_PRIMARY)
                                         .font(egui::TextStyle::Monospace)
                                         .frame(false)
@@ -34,14 +34,17 @@ impl NexusApp {
                         ui.horizontal(|ui| {
                             ui.label(RichText::new("🔍 Auto-Find Cookie").color(Colors::TEXT_PRIMARY).size(15.0).strong());
                             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                                if ui.add_sized([120.0, 34.0], theme::primary_button("Search")).clicked() {
+                                let label = if self.cookie_scan.is_some() { "Searching..." } else { "Search" };
+                                if ui.add_enabled_ui(self.cookie_scan.is_none(), |ui| {
+                                    ui.add_sized([120.0, 34.0], theme::primary_button(label))
+                                }).inner.clicked() {
                                     self.action = Action::FindCookies;
                                 }
                             });
                         });
                         
                         ui.add_space(8.0);
-                        ui.label(RichText::new("Searches Registry, Chrome, Edge, Firefox").color(Colors::TEXT_MUTED).size(12.0));
+                        ui.label(RichText::new("Searches Registry, Chrome, Chromium, Brave, Edge, Firefox").color(Colors::TEXT_MUTED).size(12.0));
                         
                         if !self.found_cookies.is_empty() {
                             ui.add_space(12.0);
@@ -54,21 +57,60 @@ impl NexusApp {
                                 .show(ui, |ui| {
                                     ui.label(RichText::new(format!(" Found {} cookie(s)", self.found_cookies.len())).color(Colors::ACCENT_GREEN).strong());
                                     
+                                    let managed_ids: std::collections::HashSet<u64> = self.data.accounts.iter()
+                                        .filter_map(|a| a.user_id)
+                                        .collect();
+                                    
                                     let cookies: Vec<_> = self.found_cookies.iter()
-                                        .map(|fc| (fc.source.clone(), fc.cookie.clone()))
+                                        .map(|fc| (fc.source(), fc.cookie.clone(), fc.user_id, fc.status_label().to_string(), fc.expires))
                                         .collect();
                                     
-                                    for (source, cookie) in cookies {
+                                    for (idx, (source, cookie, user_id, status, expires)) in cookies.into_iter().enumerate() {
+                                        let managed = user_id.is_some_and(|id| managed_ids.contains(&id));
+                                        let importable = user_id.is_some() && !managed;
+                                        
                                         ui.add_space(8.0);
                                         ui.horizontal(|ui| {
-                                            ui.label(RichText::new(&source).color(Colors::TEXT_PRIMARY).size(12.0));
+                                            let mut checked = self.found_cookies_selected.contains(&idx);
+                                            if ui.add_enabled(importable, egui::Checkbox::without_text(&mut checked)).changed() {
+                                                if checked {
+                                                    self.found_cookies_selected.insert(idx);
+                                                } else {
+                                                    self.found_cookies_selected.remove(&idx);
+                                                }
+                                            }
+                                            
+                                            ui.vertical(|ui| {
+                                                ui.label(RichText::new(status)
+                                                    .color(if user_id.is_some() { Colors::TEXT_PRIMARY } else { Colors::TEXT_MUTED })
+                                                    .size(12.0)
+                                                    .strong());
+                                                
+                                                let expiry = expires
+                                                    .and_then(|ts| chrono::DateTime::from_timestamp(ts, 0))
+                                                    .map(|dt| format!("expires {}", dt.format("%Y-%m-%d")))
+                                                    .unwrap_or_else(|| "session cookie".to_string());
+                                                ui.label(RichText::new(format!("{} • {}", source, expiry)).color(Colors::TEXT_MUTED).size(10.0));
+                                            });
+                                            
                                             ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
-                                                if ui.add(theme::primary_button("Use")).clicked() {
+                                                if ui.add(theme::primary_button("Use")).on_hover_text("Use for the selected account").clicked() {
                                                     self.action = Action::UseCookie(cookie);
                                                 }
+                                                if managed {
+                                                    theme::label_badge(ui, "Managed", Colors::ACCENT_GREEN);
+                                                }
                                             });
                                         });
                                     }
+                                    
+                                    ui.add_space(10.0);
+                                    let selected = self.found_cookies_selected.len();
+                                    ui.add_enabled_ui(selected > 0, |ui| {
+                                        if ui.add(theme::success_button(&format!("Import Selected ({})", selected))).clicked() {
+                                            self.action = Action::ImportFoundCookies;
+                                        }
+                                    });
                                 });
                         }
                     });
@@ -117,7 +159,7 @@ impl NexusApp {
                         ui.add_sized(
                             [ui.available_width(), 120.0],
                             egui::TextEdit::multiline(&mut self.import_cookie)
-                                .hint_text(RichText::new("Paste your .ROBLOSECURITY cookie here...").color(Colors::TEXT_MUTED))
+                                .hint_text(RichText::new("Paste a .ROBLOSECURITY cookie, Cookie header, cookies.txt or JSON export...").color(Colors::TEXT_MUTED))
                                 .text_color(Colors::TEXT_PRIMARY)
                                 .frame(false)
                                 .font(egui::TextStyle::Monospace)
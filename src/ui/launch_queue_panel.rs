@@ -0,0 +1,103 @@
+use eframe::egui::{self, RichText};
+use crate::api::LaunchState;
+use crate::theme::{self, Colors};
+use super::NexusApp;
+
+impl NexusApp {
+    pub fn render_launch_queue(&mut self, ctx: &egui::Context) {
+        let active = self.launch_queue.is_active();
+        let paused = self.launch_queue.is_paused();
+        let (launched, failed, total) = self.launch_queue.counts();
+        let done = self.launch_queue.items().iter().filter(|i| i.state.is_finished()).count();
+        
+        egui::Window::new("Launch Queue")
+            .title_bar(false)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::RIGHT_BOTTOM, egui::vec2(-16.0, -16.0))
+            .fixed_size(egui::vec2(340.0, 0.0))
+            .frame(egui::Frame::none()
+                .fill(Colors::BG_MEDIUM)
+                .stroke(egui::Stroke::new(1.5, Colors::ACCENT_BLUE))
+                .rounding(egui::Rounding::same(10.0))
+                .inner_margin(egui::Margin::same(14.0))
+            )
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new("▶ LAUNCH QUEUE").size(12.0).color(Colors::TEXT_PRIMARY).strong());
+                    ui.add_space(4.0);
+                    theme::label_badge(ui, &format!("{}/{}", done, total), Colors::ACCENT_BLUE);
+                    if paused {
+                        // A launch already in progress finishes first
+                        let holding = !active || self.launch_queue.is_holding();
+                        let label = if holding { "PAUSED" } else { "PAUSING" };
+                        theme::label_badge(ui, label, Colors::ACCENT_YELLOW);
+                    }
+                    
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if !active && ui.add(theme::icon_button("✕")).on_hover_text("Close").clicked() {
+                            self.launch_queue.clear_finished();
+                            self.launch_queue_show = false;
+                        }
+                    });
+                });
+                
+                ui.add_space(6.0);
+                let progress = if total == 0 { 0.0 } else { done as f32 / total as f32 };
+                ui.add(egui::ProgressBar::new(progress).desired_height(6.0).fill(Colors::ACCENT_BLUE));
+                ui.add_space(8.0);
+                
+                egui::ScrollArea::vertical()
+                    .max_height(220.0)
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        for item in self.launch_queue.items() {
+                            let (text, color) = match item.state {
+                                LaunchState::Queued => ("Queued".to_string(), Colors::TEXT_MUTED),
+                                LaunchState::ResolvingLink => ("Resolving link...".to_string(), Colors::ACCENT_BLUE),
+                                LaunchState::FetchingTicket { attempt: 1 } => ("Fetching ticket...".to_string(), Colors::ACCENT_BLUE),
+                                LaunchState::FetchingTicket { attempt } => (format!("Retrying ticket ({})...", attempt), Colors::ACCENT_YELLOW),
+                                LaunchState::WaitingForServer => ("Finding server...".to_string(), Colors::ACCENT_BLUE),
+                                LaunchState::Launched => ("Launched".to_string(), Colors::ACCENT_GREEN),
+                                LaunchState::Failed(_) => ("Failed".to_string(), Colors::ACCENT_RED),
+                                LaunchState::Cancelled => ("Cancelled".to_string(), Colors::TEXT_MUTED),
+                            };
+                            
+                            let row = ui.horizontal(|ui| {
+                                theme::draw_status_circle(ui, color, 5.0);
+                                ui.add_space(4.0);
+                                ui.label(RichText::new(&item.job.account).size(12.0).color(Colors::TEXT_PRIMARY));
+                                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                    ui.label(RichText::new(text).size(11.0).color(color));
+                                });
+                            });
+                            if let LaunchState::Failed(ref error) = item.state {
+                                row.response.on_hover_text(error);
+                            }
+                        }
+                    });
+                
+                ui.add_space(8.0);
+                ui.horizontal(|ui| {
+                    if active {
+                        let label = if paused { "▶ Resume" } else { "⏸ Pause" };
+                        if ui.add(theme::secondary_button(label)).clicked() {
+                            self.launch_queue.set_paused(!paused);
+                        }
+                        if ui.add(theme::danger_button("Cancel")).clicked() {
+                            self.launch_queue.cancel();
+                        }
+                    } else {
+                        ui.label(RichText::new(format!("{} launched, {} failed", launched, failed))
+                            .size(11.0).color(Colors::TEXT_MUTED));
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            if failed > 0 && ui.add(theme::primary_button("↻ Retry Failed")).clicked() {
+                                let delay = std::time::Duration::from_secs(self.batch_delay as u64);
+                                self.launch_queue.retry_failed(delay);
+                            }
+                        });
+                    }
+                });
+            });
+    }
+}
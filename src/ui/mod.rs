@@ -4,13 +4,22 @@ mod import_cookie;
 mod games_tab;
 mod server_browser_tab;
 mod account_utils_tab;
+mod account_details_tab;
+mod launch_queue_panel;
+mod preset_editor;
 
 use eframe::egui::{self, RichText};
-use crate::account::{AccountStatus, AppData, RecentGame, RobloxAccount, UserGame};
-use crate::api::{RobloxApi, ServerBrowser};
-use crate::auth::{CookieFinder, FoundCookie, MultiInstanceManager};
+use crate::account::{push_value_snapshot, AccountDetails, AccountStatus, AppData, CollectibleItem, LaunchPreset, RecentGame, RobloxAccount, UserGame, ValueSnapshot};
+use crate::api::privacy::PrivacySetting;
+use crate::api::clients::ClientTracker;
+use crate::api::{AccountInfo, BatchTask, LinkTarget, LaunchMode, LaunchOptions, LaunchQueue, LaunchRequest, LaunchState, LauncherBackend, QueueJob, RobloxApi, ServerBrowser};
+use crate::auth::{CookieCheck, CookieFinder, FoundCookie, MultiInstanceManager, ScanEvent};
+use crate::platform::{self, Platform};
+use crate::protocol::{Handoff, NexusLink};
 use crate::theme::Colors;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::mpsc::Receiver;
+use std::sync::Arc;
 
 #[derive(Default, PartialEq, Clone, Copy)]
 pub enum Tab {
@@ -21,6 +30,7 @@ pub enum Tab {
     Servers,
     AccountUtils,
     ImportCookie,
+    AccountDetails,
     Settings,
     About,
 }
@@ -31,16 +41,26 @@ pub enum Action {
     None,
     VerifyAccount(usize),
     LaunchAccount(usize),
+    CloseClient(usize),
+    CloseAllClients,
     DeleteAccount(usize),
     SelectAccount(usize),
     ImportCookie,
     FindCookies,
     UseCookie(String),
+    ImportFoundCookies,
     SelectGame(String, String),
     ToggleFavoriteGame(String),
     ToggleMultiInstance,
     FetchAccountInfo(usize),
+    FetchAllAccountInfo,
+    OpenAccountDetails(usize),
     BatchLaunch,
+    BatchLaunchTogether,
+    LaunchPreset(usize),
+    EditPreset(Option<usize>),
+    SavePreset,
+    DeletePreset(usize),
     RefreshAllCookies,
     RefreshPresence,
     FetchServers,
@@ -59,15 +79,127 @@ pub enum Action {
     SendFriendRequestByName(usize, String),
     FetchAvatars,
     StartBrowserLogin,
+    ReloginAccount(usize),
+    OpenAccountBrowser(usize),
+    CloseAccountBrowser(usize),
     ImportCookieAsNewAccount,
     ShowCookieModal(usize),
     UpdateAccountCookie(usize),
     FollowUser(usize),
+    FetchFriends(usize),
+    JoinFriend(usize, u64),
+    FetchFriendRequests(usize),
+    AcceptFriendRequest(usize, u64),
+    DeclineFriendRequest(usize, u64),
+    DeclineAllFriendRequests(usize),
+    FetchBlockedUsers(usize),
+    UnblockUser(usize, u64),
+    CopyBlockList(usize, usize),
+    FetchPrivacySettings(usize),
+    ApplyPrivacySettings(usize),
+    ApplyPrivacyToSelected,
+    RotateSelectedPasswords,
+    ResolvePendingPassword(usize, bool),
     ImportDroppedCookie(String),
+    OpenLink(LinkTarget),
+    OpenNexusLink(String),
+    RegisterLinkHandler,
     AddUserGame(String, String),
     RemoveUserGame(String),
 }
 
+/// Everything one account info refresh gets from Roblox
+pub struct AccountFetch {
+    info: AccountInfo,
+    details: Option<AccountDetails>,
+    /// `None` when the inventory is private
+    collectibles: Option<Vec<CollectibleItem>>,
+}
+
+/// A "fetch all" pass running in the background, with its tally so far
+pub struct InfoFetchRun {
+    task: BatchTask<(u64, Result<AccountFetch, String>)>,
+    updated: usize,
+    skipped: usize,
+    failed: usize,
+    warnings: Vec<String>,
+}
+
+/// Privacy settings sent to one account: the fields that took, and errors for the rest
+pub struct PrivacyOutcome {
+    applied: Vec<(&'static str, String)>,
+    errors: Vec<String>,
+}
+
+/// Username, new password and Roblox's answer for one account of a password rotation
+pub type RotationResult = (String, String, Result<String, String>);
+
+/// "Apply to selected" privacy changes running in the background
+pub struct PrivacyRun {
+    task: BatchTask<(String, u64, PrivacyOutcome)>,
+    total: usize,
+    applied: usize,
+    failures: Vec<String>,
+}
+
+/// An account that has to be found again later, after accounts may have been deleted or
+/// reordered: by user ID, or by username until it has been verified
+#[derive(Clone, Debug, PartialEq)]
+pub enum AccountRef {
+    Id(u64),
+    Name(String),
+}
+
+impl AccountRef {
+    pub fn of(account: &RobloxAccount) -> Self {
+        match account.user_id {
+            Some(id) => AccountRef::Id(id),
+            None => AccountRef::Name(account.username.clone()),
+        }
+    }
+    
+    pub fn find(&self, accounts: &[RobloxAccount]) -> Option<usize> {
+        match self {
+            AccountRef::Id(id) => accounts.iter().position(|a| a.user_id == Some(*id)),
+            AccountRef::Name(name) => accounts.iter().position(|a| a.user_id.is_none() && a.username == *name),
+        }
+    }
+}
+
+/// A block list being copied to another account in the background
+pub struct BlockCopyRun {
+    task: BatchTask<Result<(), String>>,
+    target_name: String,
+    target_id: u64,
+    /// Users the target had blocked already
+    already: usize,
+    blocked: usize,
+    failed: usize,
+}
+
+/// Sub-tabs of the account detail view
+#[derive(Default, PartialEq, Clone, Copy)]
+pub enum DetailsTab {
+    #[default]
+    Profile,
+    Inventory,
+    Friends,
+    Requests,
+}
+
+impl DetailsTab {
+    pub const ALL: [DetailsTab; 4] = [DetailsTab::Profile, DetailsTab::Inventory, DetailsTab::Friends, DetailsTab::Requests];
+    
+    pub fn label(&self) -> &'static str {
+        match self {
+            DetailsTab::Profile => "👤 Profile",
+            DetailsTab::Inventory => "💎 Inventory",
+            DetailsTab::Friends => "👥 Friends",
+            DetailsTab::Requests => "📨 Requests",
+        }
+    }
+}
+
 #[derive(Default, PartialEq, Clone, Copy)]
 pub enum AccountSort {
     #[default]
@@ -104,15 +236,25 @@ impl AccountSort {
 }
 
 pub struct NexusApp {
+    pub platform: Arc<dyn Platform>,
+    /// Clients launched from this app
+    pub client_tracker: Arc<ClientTracker>,
     pub data: AppData,
     pub tab: Tab,
     pub selected: Option<usize>,
     
     pub import_cookie: String,
     pub found_cookies: Vec<FoundCookie>,
+    /// Indices into `found_cookies` ticked for bulk import
+    pub found_cookies_selected: HashSet<usize>,
+    /// Background search filling `found_cookies`
+    pub cookie_scan: Option<Receiver<ScanEvent>>,
+    pub details_tab: DetailsTab,
     
     pub place_id: String,
     pub selected_game_name: String,
+    /// Server, private server or user from a pasted link, launched instead of `place_id`
+    pub link_target: Option<LinkTarget>,
     
     pub delete_confirm: Option<usize>,
     pub status: String,
@@ -126,16 +268,30 @@ pub struct NexusApp {
     pub multi_instance: MultiInstanceManager,
     
     pub batch_selected: HashSet<usize>,
-    #[allow(dead_code)]
-    pub batch_launching: bool,
     pub batch_delay: u32,
+    pub launch_queue: LaunchQueue,
+    pub launch_queue_show: bool,
+    pub info_fetch: Option<InfoFetchRun>,
+    /// Single-account refreshes in the background, by user ID
+    pub account_fetches: HashMap<u64, BatchTask<Result<AccountFetch, String>>>,
+    /// Preset being created or edited, and the index it replaces
+    pub preset_draft: Option<LaunchPreset>,
+    pub preset_edit_idx: Option<usize>,
+    /// Presets named with `--preset` here or in later instances, launched one per frame
+    pub pending_presets: VecDeque<String>,
+    /// `nexus://` links from the command line or later instances, opened one per frame
+    pub pending_links: VecDeque<String>,
+    /// Links, presets and show requests from later instances
+    pub handoff: Option<Receiver<Handoff>>,
     
     pub server_browser: ServerBrowser,
     
     pub util_target_user: String,
-    #[allow(dead_code)]
     pub util_current_password: String,
     pub util_new_password: String,
+    /// Outcome of the last bulk password rotation: username and error, if any
+    pub password_rotation_report: Vec<(String, Option<String>)>,
+    pub password_rotation: Option<BatchTask<RotationResult>>,
     pub util_new_display_name: String,
     
     pub avatar_textures: std::collections::HashMap<u64, egui::TextureHandle>,
@@ -146,8 +302,17 @@ pub struct NexusApp {
     
     pub startup_fetch_done: bool,
     pub last_presence_refresh: std::time::Instant,
+    pub last_client_scan: std::time::Instant,
     
     pub browser_login_session: Option<crate::auth::WebLoginSession>,
+    pub browser_login_status: Option<crate::auth::BrowserStatus>,
+    /// Open a fresh login after each success until the user stops
+    pub login_queue_enabled: bool,
+    pub login_queue_added: u32,
+    /// Account whose cookie the active browser session will replace, if any
+    pub relogin_account: Option<AccountRef>,
+    /// Browsers signed in as an account, keyed by user ID
+    pub account_browsers: std::collections::HashMap<u64, crate::auth::AccountBrowserSession>,
     
     pub cookie_modal_account_idx: Option<usize>,
     pub cookie_modal_value: String,
@@ -157,6 +322,24 @@ pub struct NexusApp {
     pub follow_user_target: String,
     pub follow_user_account_idx: Option<usize>,
     
+    /// Friends lists for the detail view, keyed by the account's user ID
+    pub friends: std::collections::HashMap<u64, Vec<crate::api::FriendInfo>>,
+    pub friends_search: String,
+    pub friends_online_only: bool,
+    /// Pending incoming friend requests, keyed by the receiving account's user ID
+    pub friend_requests: std::collections::HashMap<u64, Vec<crate::api::FriendRequest>>,
+    /// Block lists for the Utilities tab, keyed by the account's user ID
+    pub blocked_users: std::collections::HashMap<u64, Vec<crate::api::BlockedUser>>,
+    pub block_copy_target: Option<usize>,
+    pub block_copy: Option<BlockCopyRun>,
+    /// Privacy values as last read from Roblox, keyed by user ID, then by setting field
+    pub privacy_settings: std::collections::HashMap<u64, std::collections::HashMap<String, String>>,
+    /// Values being edited in the Privacy section, keyed by setting field
+    pub privacy_draft: std::collections::HashMap<String, String>,
+    /// Account the draft was loaded from
+    pub privacy_draft_account: Option<u64>,
+    pub privacy_run: Option<PrivacyRun>,
+    
     pub drag_drop_active: bool,
     
     pub add_user_game_place_id: String,
@@ -168,23 +351,32 @@ pub struct NexusApp {
 
 impl NexusApp {
     pub fn new() -> Self {
-        let data = AppData::load();
+        Self::with_platform(AppData::load(), platform::native())
+    }
+    
+    pub fn with_platform(data: AppData, platform: Arc<dyn Platform>) -> Self {
         let place_id = data.last_place_id.clone();
         let multi_enabled = data.multi_instance_enabled;
         let batch_delay = if data.batch_launch_delay == 0 { 5 } else { data.batch_launch_delay };
         
-        let mut multi_instance = MultiInstanceManager::new();
+        let mut multi_instance = MultiInstanceManager::new(platform.clone());
         if multi_enabled {
             let _ = multi_instance.enable();
         }
         
         Self {
+            platform,
+            client_tracker: Arc::new(ClientTracker::default()),
             data,
             tab: Tab::Accounts,
             selected: None,
             import_cookie: String::new(),
             found_cookies: Vec::new(),
+            found_cookies_selected: HashSet::new(),
+            cookie_scan: None,
+            details_tab: DetailsTab::default(),
             place_id,
+            link_target: None,
             selected_game_name: String::new(),
             delete_confirm: None,
             status: String::new(),
@@ -195,12 +387,22 @@ impl NexusApp {
             account_sort: AccountSort::Default,
             multi_instance,
             batch_selected: HashSet::new(),
-            batch_launching: false,
             batch_delay,
+            launch_queue: LaunchQueue::default(),
+            launch_queue_show: false,
+            info_fetch: None,
+            account_fetches: HashMap::new(),
+            preset_draft: None,
+            preset_edit_idx: None,
+            pending_presets: VecDeque::new(),
+            pending_links: VecDeque::new(),
+            handoff: None,
             server_browser: ServerBrowser::new(),
             util_target_user: String::new(),
             util_current_password: String::new(),
             util_new_password: String::new(),
+            password_rotation_report: Vec::new(),
+            password_rotation: None,
             util_new_display_name: String::new(),
             avatar_textures: std::collections::HashMap::new(),
             avatars_loading: false,
@@ -208,13 +410,30 @@ impl NexusApp {
             game_icons_loaded: false,
             startup_fetch_done: false,
             last_presence_refresh: std::time::Instant::now(),
+            last_client_scan: std::time::Instant::now(),
             browser_login_session: None,
+            browser_login_status: None,
+            login_queue_enabled: false,
+            login_queue_added: 0,
+            relogin_account: None,
+            account_browsers: std::collections::HashMap::new(),
             cookie_modal_account_idx: None,
             cookie_modal_value: String::new(),
             cookie_modal_show: false,
             follow_user_show: false,
             follow_user_target: String::new(),
             follow_user_account_idx: None,
+            friends: std::collections::HashMap::new(),
+            friends_search: String::new(),
+            friends_online_only: false,
+            friend_requests: std::collections::HashMap::new(),
+            blocked_users: std::collections::HashMap::new(),
+            block_copy_target: None,
+            block_copy: None,
+            privacy_settings: std::collections::HashMap::new(),
+            privacy_draft: std::collections::HashMap::new(),
+            privacy_draft_account: None,
+            privacy_run: None,
             drag_drop_active: false,
             add_user_game_place_id: String::new(),
             add_user_game_name: String::new(),
@@ -343,38 +562,82 @@ impl NexusApp {
             }
             
             Action::LaunchAccount(idx) => {
-                let account_data = self.data.accounts.get(idx).map(|a| {
-                    (a.username.clone(), a.cookie.clone())
-                });
+                let Some(account) = self.data.accounts.get(idx).cloned() else { return };
+                let Some(cookie) = account.cookie.clone() else { return };
+                let username = account.username.clone();
                 
-                if let Some((username, cookie_opt)) = account_data {
-                    if let Some(cookie) = cookie_opt {
-                        self.set_status(format!("⏳ Launching Roblox instance: {} - please wait...", username), false);
-                        
-                        let place = if self.place_id.is_empty() { None } else { Some(self.place_id.clone()) };
-                        
-                        self.data.last_place_id = self.place_id.clone();
-                        self.data.save();
-                        
-                        let result = if self.multi_instance.is_enabled() {
-                            RobloxApi::launch_multi_instance(&cookie, place.as_deref())
-                        } else {
-                            RobloxApi::set_account_and_launch(&cookie, place.as_deref())
-                        };
-                        
-                        match result {
-                            Ok(_) => {
-                                if !self.place_id.is_empty() {
-                                    self.add_recent_game(&self.place_id.clone());
-                                }
-                                self.set_status(format!("✅ Launched {} - Roblox is starting up", username), false);
-                            }
-                            Err(e) => self.set_status(format!("Launch failed: {}", e), true),
-                        }
+                self.set_status(format!("⏳ Launching Roblox instance: {} - please wait...", username), false);
+                
+                let mode = match self.account_launch_mode(&account, &cookie) {
+                    Ok(mode) => mode,
+                    Err(e) => {
+                        self.set_status(format!("Launch failed: {}", e), true);
+                        return;
+                    }
+                };
+                
+                self.data.last_place_id = self.place_id.clone();
+                self.data.save();
+                self.launch_with_mode(&username, &cookie, mode);
+            }
+            
+            Action::OpenNexusLink(uri) => {
+                let link = match NexusLink::parse(&uri) {
+                    Ok(link) => link,
+                    Err(e) => {
+                        self.set_status(format!("Can't open link: {}", e), true);
+                        return;
                     }
+                };
+                let Some(account) = self.find_link_account(&link.account).and_then(|idx| self.data.accounts.get(idx)).cloned() else {
+                    self.set_status(format!("Can't open link: no account matches '{}'", link.account), true);
+                    return;
+                };
+                let Some(cookie) = account.cookie.clone() else {
+                    self.set_status(format!("Can't open link: {} has no cookie", account.username), true);
+                    return;
+                };
+                
+                // Without a place the account's own launch defaults apply
+                let mode = match (link.place_id, link.job_id) {
+                    (Some(place_id), Some(job_id)) => LaunchMode::Job { place_id, job_id },
+                    (Some(place_id), None) => LaunchMode::Place { place_id },
+                    (None, _) => match self.account_launch_mode(&account, &cookie) {
+                        Ok(mode) => mode,
+                        Err(e) => {
+                            self.set_status(format!("Launch failed: {}", e), true);
+                            return;
+                        }
+                    },
+                };
+                
+                self.set_status(format!("⏳ Launching {} from link...", account.username), false);
+                self.launch_with_mode(&account.username, &cookie, mode);
+            }
+            
+            Action::RegisterLinkHandler => {
+                match self.platform.register_uri_scheme() {
+                    Ok(()) => self.set_status("✅ nexus:// links now open in Nexus", false),
+                    Err(e) => self.set_status(format!("Couldn't register links: {}", e), true),
+                }
+            }
+            
+            Action::CloseClient(idx) => {
+                let Some(username) = self.data.accounts.get(idx).map(|a| a.username.clone()) else {
+                    return;
+                };
+                match self.client_tracker.close(&username, self.platform.as_ref()) {
+                    Ok(true) => self.set_status(format!("Closed {}'s client", username), false),
+                    Ok(false) => self.set_status(format!("{} has no running client", username), true),
+                    Err(e) => self.set_status(format!("Failed to close {}'s client: {}", username, e), true),
                 }
             }
             
+            Action::CloseAllClients => {
+                let closed = self.client_tracker.close_all(self.platform.as_ref());
+                self.set_status(format!("Closed {} client(s)", closed), false);
+            }
+            
             Action::DeleteAccount(idx) => {
                 if idx < self.data.accounts.len() {
                     let name = self.data.accounts[idx].username.clone();
@@ -386,12 +649,16 @@ impl NexusApp {
             }
             
             Action::ImportCookie => {
-                let cookie = self.import_cookie.trim().to_string();
-                if cookie.is_empty() {
+                if self.import_cookie.trim().is_empty() {
                     self.set_status("Paste a cookie first", true);
                     return;
                 }
                 
+                let Some(cookie) = crate::auth::parse_cookie(&self.import_cookie) else {
+                    self.set_status("No .ROBLOSECURITY cookie found in the pasted text", true);
+                    return;
+                };
+                
                 if let Some(idx) = self.selected {
                     // Get username first to avoid borrow conflict
                     let username = self.data.accounts.get(idx).map(|a| a.username.clone());
@@ -425,13 +692,65 @@ impl NexusApp {
             }
             
             Action::FindCookies => {
+                if self.cookie_scan.is_some() {
+                    return;
+                }
                 self.set_status("Searching...", false);
-                self.found_cookies = CookieFinder::find_all_cookies();
+                self.found_cookies.clear();
+                self.found_cookies_selected.clear();
+                self.cookie_scan = Some(CookieFinder::spawn_scan());
+            }
+            
+            Action::ImportFoundCookies => {
+                let mut selected: Vec<usize> = self.found_cookies_selected.drain().collect();
+                selected.sort();
+                
+                let mut added = Vec::new();
+                for idx in selected {
+                    let Some(found) = self.found_cookies.get(idx) else { continue };
+                    let (Some(user_id), Some(username)) = (found.user_id, found.username.clone()) else { continue };
+                    
+                    if self.data.accounts.iter().any(|a| a.user_id == Some(user_id)) {
+                        continue;
+                    }
+                    
+                    let account = RobloxAccount {
+                        username: username.clone(),
+                        password: String::new(),
+                        pending_password: None,
+                        cookie: Some(found.cookie.clone()),
+                        user_id: Some(user_id),
+                        display_name: None,
+                        last_login: Some(chrono::Local::now().format("%Y-%m-%d %H:%M").to_string()),
+                        status: AccountStatus::Valid,
+                        notes: String::new(),
+                        group: String::new(),
+                        robux: None,
+                        friends_count: None,
+                        is_premium: None,
+                        collectibles_count: None,
+                        last_info_fetch: None,
+                        presence: None,
+                        avatar_url: None,
+                        details: None,
+                        collectibles: Vec::new(),
+                        value_history: Vec::new(),
+                        robux_history: Vec::new(),
+                        pending_friend_requests: None,
+                        launch_defaults: Default::default(),
+                        last_server: None,
+                    };
+                    
+                    self.data.accounts.push(account);
+                    added.push(username);
+                }
                 
-                if self.found_cookies.is_empty() {
-                    self.set_status("No cookies found", true);
+                if added.is_empty() {
+                    self.set_status("Nothing to import - selected sessions are invalid or already added", true);
                 } else {
-                    self.set_status(format!("Found {} cookie(s)", self.found_cookies.len()), false);
+                    self.data.save();
+                    self.set_status(format!("✅ Imported {} account(s): {}", added.len(), added.join(", ")), false);
+                    self.fetch_presence_and_avatars();
                 }
             }
             
@@ -442,6 +761,7 @@ impl NexusApp {
             
             Action::SelectGame(place_id, game_name) => {
                 self.place_id = place_id;
+                self.link_target = None;
                 self.selected_game_name = game_name;
                 self.data.last_place_id = self.place_id.clone();
                 self.data.save();
@@ -478,91 +798,130 @@ impl NexusApp {
             }
             
             Action::FetchAccountInfo(idx) => {
-                let account_data = self.data.accounts.get(idx).map(|a| {
-                    (a.username.clone(), a.cookie.clone(), a.user_id)
-                });
-                
-                if let Some((username, Some(cookie), Some(user_id))) = account_data {
-                    self.set_status(format!("Fetching info for {}...", username), false);
-                    
-                    match RobloxApi::get_account_info(&cookie, user_id) {
-                        Ok(info) => {
-                            if let Some(account) = self.data.accounts.get_mut(idx) {
-                                account.robux = Some(info.robux);
-                                account.friends_count = Some(info.friends_count);
-                                account.is_premium = Some(info.is_premium);
-                                account.last_info_fetch = Some(chrono::Local::now().format("%Y-%m-%d %H:%M").to_string());
-                            }
-                            
-                    if let Ok(collectibles) = RobloxApi::get_inventory_info(user_id) {
-                                if let Some(account) = self.data.accounts.get_mut(idx) {
-                                    account.collectibles_count = Some(collectibles);
-                                }
-                            }
-                            
-                            self.data.save();
-                            self.set_status(format!("[OK] Updated info for {}", username), false);
-                        }
-                        Err(e) => {
-                            self.set_status(format!("Failed to fetch info: {}", e), true);
-                        }
-                    }
-                } else {
+                let Some(account) = self.data.accounts.get(idx) else { return };
+                let username = account.username.clone();
+                let (Some(cookie), Some(user_id)) = (account.cookie.clone(), account.user_id) else {
                     self.set_status("Verify account first", true);
+                    return;
+                };
+                if self.account_fetches.contains_key(&user_id) {
+                    return;
                 }
+                
+                self.set_status(format!("Fetching info for {}...", username), false);
+                let task = BatchTask::spawn(vec![cookie], move |cookie: String| Self::request_account_info(&cookie, user_id));
+                self.account_fetches.insert(user_id, task);
             }
             
-            Action::BatchLaunch => {
-                if self.batch_selected.is_empty() {
-                    self.set_status("Select accounts to batch launch", true);
+            Action::FetchAllAccountInfo => {
+                if self.info_fetch.is_some() {
+                    self.set_status("Already fetching account info", true);
                     return;
                 }
                 
-                if !self.multi_instance.is_enabled() {
-                    if self.multi_instance.enable().is_err() {
-                        self.set_status("Failed to enable multi-instance for batch launch", true);
-                        return;
+                let mut jobs = Vec::new();
+                let mut skipped = 0;
+                for account in &self.data.accounts {
+                    let (Some(cookie), Some(user_id)) = (account.cookie.clone(), account.user_id) else { continue };
+                    // Cached results younger than this are reused
+                    if account.details.is_some() && account.info_is_fresh(15) {
+                        skipped += 1;
+                        continue;
                     }
-                    self.data.multi_instance_enabled = true;
-                    self.data.save();
+                    jobs.push((user_id, cookie));
                 }
                 
-                let place = if self.place_id.is_empty() { None } else { Some(self.place_id.clone()) };
-                let delay_secs = self.batch_delay;
-                let mut launched = 0;
-                let mut failed = 0;
+                self.set_status(format!("Fetching info for {} account(s)...", jobs.len()), false);
+                let task = BatchTask::spawn(jobs, |(user_id, cookie): (u64, String)| {
+                    (user_id, Self::request_account_info(&cookie, user_id))
+                });
+                self.info_fetch = Some(InfoFetchRun { task, updated: 0, skipped, failed: 0, warnings: Vec::new() });
+                self.check_info_fetch();
+            }
+            
+            Action::OpenAccountDetails(idx) => {
+                self.selected = Some(idx);
+                self.tab = Tab::AccountDetails;
+                
+                let needs_fetch = self.data.accounts.get(idx).is_some_and(|a| a.details.is_none());
+                if needs_fetch {
+                    self.action = Action::FetchAccountInfo(idx);
+                }
+            }
+            
+            action @ (Action::BatchLaunch | Action::BatchLaunchTogether) => {
+                let together = matches!(action, Action::BatchLaunchTogether);
+                if self.batch_selected.is_empty() {
+                    self.set_status("Select accounts to batch launch", true);
+                    return;
+                }
                 
-                let indices: Vec<usize> = self.batch_selected.iter().copied().collect();
+                let mut indices: Vec<usize> = self.batch_selected.iter().copied().collect();
+                indices.sort_unstable();
                 
-                let accounts_data: Vec<_> = indices.iter()
-                    .filter_map(|idx| self.data.accounts.get(*idx).map(|a| (a.username.clone(), a.cookie.clone())))
-                    .collect();
+                let target = match self.selected_target() {
+                    Ok(LinkTarget::Launch(mode)) if together && mode.place_id().is_none() => {
+                        self.set_status("Select a game to launch accounts together", true);
+                        return;
+                    }
+                    Ok(target) => target,
+                    Err(e) => {
+                        self.set_status(e, true);
+                        return;
+                    }
+                };
                 
-                for (i, (username, cookie_opt)) in accounts_data.iter().enumerate() {
-                    if let Some(cookie) = cookie_opt {
-                        self.set_status(format!("⏳ Launching {} ({}/{}) - please wait...", username, i + 1, accounts_data.len()), false);
-                        
-                        match RobloxApi::launch_multi_instance(cookie, place.as_deref()) {
-                            Ok(_) => {
-                                launched += 1;
-                                if !self.place_id.is_empty() {
-                                    self.add_recent_game(&self.place_id.clone());
-                                }
-                            }
-                            Err(_) => failed += 1,
-                        }
-                        
-                        if i < accounts_data.len() - 1 {
-                            std::thread::sleep(std::time::Duration::from_secs(delay_secs as u64));
-                        }
+                match self.queue_launches(&indices, target, self.batch_delay, true, together) {
+                    Ok(count) => {
+                        self.batch_selected.clear();
+                        let how = if together { " into one server" } else { "" };
+                        self.set_status(format!("⏳ Queued {} account(s) for launch{}", count, how), false);
                     }
+                    Err(e) => self.set_status(e, true),
+                }
+            }
+            
+            Action::LaunchPreset(idx) => {
+                let name = self.data.launch_presets.get(idx).map(|p| p.name.clone()).unwrap_or_default();
+                match self.launch_preset(idx) {
+                    Ok(count) => self.set_status(format!("⏳ Queued {} account(s) from '{}'", count, name), false),
+                    Err(e) => self.set_status(e, true),
+                }
+            }
+            
+            Action::EditPreset(idx) => {
+                self.preset_draft = match idx.and_then(|i| self.data.launch_presets.get(i)) {
+                    Some(preset) => Some(preset.clone()),
+                    None => Some(self.preset_from_selection()),
+                };
+                self.preset_edit_idx = idx;
+            }
+            
+            Action::SavePreset => {
+                let Some(mut preset) = self.preset_draft.clone() else { return };
+                preset.name = preset.name.trim().to_string();
+                if preset.name.is_empty() {
+                    self.set_status("Give the preset a name", true);
+                    return;
+                }
+                if preset.user_ids.is_empty() {
+                    self.set_status("A preset needs at least one account", true);
+                    return;
                 }
                 
-                self.batch_selected.clear();
-                if failed == 0 {
-                    self.set_status(format!("✅ Launched {} accounts - Roblox is starting up", launched), false);
-                } else {
-                    self.set_status(format!("Launched {}, {} failed", launched, failed), true);
+                let name = preset.name.clone();
+                self.data.save_preset(preset, self.preset_edit_idx);
+                self.data.save();
+                self.preset_draft = None;
+                self.preset_edit_idx = None;
+                self.set_status(format!("✅ Saved preset '{}'", name), false);
+            }
+            
+            Action::DeletePreset(idx) => {
+                if idx < self.data.launch_presets.len() {
+                    let preset = self.data.launch_presets.remove(idx);
+                    self.data.save();
+                    self.set_status(format!("Deleted preset '{}'", preset.name), false);
                 }
             }
             
@@ -635,7 +994,7 @@ impl NexusApp {
                                         }
                                     }
                                     
-                                    account.presence = Some(p);
+                                    account.set_presence(p);
                                     updated += 1;
                                 }
                             }
@@ -864,8 +1223,8 @@ impl NexusApp {
                         
                         match crate::api::get_random_server(&self.place_id) {
                             Ok(job_id) => {
-                                let multi = self.multi_instance.is_enabled();
-                                match RobloxApi::launch_with_job_id(&cookie, Some(&self.place_id), Some(&job_id), multi) {
+                                let options = self.launch_options(&username);
+                                match RobloxApi::launch_with_job_id(&cookie, Some(&self.place_id), Some(&job_id), &options) {
                                     Ok(_) => {
                                         self.add_recent_game(&self.place_id.clone());
                                         self.set_status(format!("✅ Launched {} to random server - Roblox is starting up", username), false);
@@ -890,13 +1249,13 @@ impl NexusApp {
                     if let Some(cookie) = cookie_opt {
                         if let Some(ref place_id) = self.server_browser.current_place_id.clone() {
                             if let Some(server) = server {
-                                let multi = self.multi_instance.is_enabled();
+                                let options = self.launch_options(&username);
                                 
                                 if server.is_vip() {
                                     if let Some(access_code) = &server.access_code {
                                         self.set_status(format!("⏳ Joining VIP server as {} - please wait...", username), false);
                                         
-                                        match RobloxApi::launch_to_private_server(&cookie, place_id, access_code, None, multi) {
+                                        match RobloxApi::launch_to_private_server(&cookie, place_id, access_code, None, &options) {
                                             Ok(_) => {
                                                 self.add_recent_game(place_id);
                                                 let server_name = server.name.as_deref().unwrap_or("VIP Server");
@@ -914,7 +1273,7 @@ impl NexusApp {
                                 } else {
                                     self.set_status(format!("⏳ Joining server as {} - please wait...", username), false);
                                     
-                                    match RobloxApi::launch_with_job_id(&cookie, Some(place_id), Some(&server.id), multi) {
+                                    match RobloxApi::launch_with_job_id(&cookie, Some(place_id), Some(&server.id), &options) {
                                         Ok(_) => {
                                             self.add_recent_game(place_id);
                                             self.set_status(format!("✅ {} joining server - Roblox is starting up", username), false);
@@ -952,10 +1311,10 @@ impl NexusApp {
                     if let Some((username, cookie_opt)) = account_data {
                         if let Some(cookie) = cookie_opt {
                             if let Some(ref place_id) = self.server_browser.current_place_id.clone() {
-                                let multi = self.multi_instance.is_enabled();
+                                let options = self.launch_options(&username);
                                 self.set_status(format!("⏳ Joining VIP server as {} - please wait...", username), false);
                                 
-                                match RobloxApi::launch_to_private_server(&cookie, place_id, &access_code, None, multi) {
+                                match RobloxApi::launch_to_private_server(&cookie, place_id, &access_code, None, &options) {
                                     Ok(_) => {
                                         self.add_recent_game(place_id);
                                         self.set_status(format!("✅ {} joining VIP server - Roblox is starting up", username), false);
@@ -982,6 +1341,27 @@ impl NexusApp {
                     return;
                 }
                 
+                // Any other kind of Roblox link launches straight to what it points at
+                if let (Some(target), Some((username, Some(cookie)))) = (crate::api::resolve_link(&input), account_data.clone()) {
+                    self.set_status(format!("⏳ Joining as {} - please wait...", username), false);
+                    let result = target.clone().with_place(&self.place_id).launch_mode(&cookie)
+                        .and_then(|mode| {
+                            RobloxApi::launch(&cookie, LaunchRequest::new(mode.clone()), &self.launch_options(&username))
+                                .map(|_| mode)
+                        });
+                    match result {
+                        Ok(mode) => {
+                            if let Some(place_id) = mode.place_id() {
+                                self.add_recent_game(&place_id.to_string());
+                            }
+                            self.set_status(format!("✅ {} joining {} - Roblox is starting up", username, target.describe()), false);
+                            self.server_browser.private_server_input.clear();
+                        }
+                        Err(e) => self.set_status(format!("Launch failed: {}", e), true),
+                    }
+                    return;
+                }
+                
                 if let Some((username, cookie_opt)) = account_data {
                     if let Some(cookie) = cookie_opt {
                         if let Some(parsed) = crate::api::PrivateServerLink::parse(&input) {
@@ -996,12 +1376,12 @@ impl NexusApp {
                             
                             self.set_status(format!("⏳ Joining private server as {} - please wait...", username), false);
                             
-                            let multi = self.multi_instance.is_enabled();
+                            let options = self.launch_options(&username);
                             
                             if !parsed.link_code.is_empty() {
                                 match crate::api::get_access_code_from_link(&cookie, &place_id, &parsed.link_code) {
                                     Ok(access_code) => {
-                                        match RobloxApi::launch_to_private_server(&cookie, &place_id, &access_code, Some(&parsed.link_code), multi) {
+                                        match RobloxApi::launch_to_private_server(&cookie, &place_id, &access_code, Some(&parsed.link_code), &options) {
                                             Ok(_) => {
                                                 self.add_recent_game(&place_id);
                                                 self.set_status(format!("✅ {} joining private server - Roblox is starting up", username), false);
@@ -1011,7 +1391,7 @@ impl NexusApp {
                                         }
                                     }
                                     Err(e) => {
-                                        match RobloxApi::launch_to_private_server(&cookie, &place_id, &parsed.link_code, None, multi) {
+                                        match RobloxApi::launch_to_private_server(&cookie, &place_id, &parsed.link_code, None, &options) {
                                             Ok(_) => {
                                                 self.add_recent_game(&place_id);
                                                 self.set_status(format!("✅ {} joining private server - Roblox is starting up", username), false);
@@ -1022,7 +1402,7 @@ impl NexusApp {
                                     }
                                 }
                             } else if let Some(access_code) = parsed.access_code {
-                                match RobloxApi::launch_to_private_server(&cookie, &place_id, &access_code, None, multi) {
+                                match RobloxApi::launch_to_private_server(&cookie, &place_id, &access_code, None, &options) {
                                     Ok(_) => {
                                         self.add_recent_game(&place_id);
                                         self.set_status(format!("✅ {} joining private server - Roblox is starting up", username), false);
@@ -1066,35 +1446,62 @@ impl NexusApp {
             }
             
             Action::ChangePassword(idx) => {
-                let account_data = self.data.accounts.get(idx).map(|a| {
-                    (a.username.clone(), a.cookie.clone(), a.password.clone())
-                });
+                let new_pass = self.util_new_password.clone();
+                if new_pass.is_empty() {
+                    self.set_status("Enter a new password", true);
+                    return;
+                }
                 
-                if let Some((username, cookie_opt, current_pass)) = account_data {
-                    if let Some(cookie) = cookie_opt {
-                        let new_pass = self.util_new_password.clone();
-                        
-                        if new_pass.is_empty() {
-                            self.set_status("Enter a new password", true);
-                            return;
-                        }
-                        
-                        self.set_status(format!("Changing password for {}...", username), false);
-                        
-                        match RobloxApi::change_password(&cookie, &current_pass, &new_pass) {
-                            Ok(new_cookie) => {
-                                if let Some(account) = self.data.accounts.get_mut(idx) {
-                                    account.password = new_pass;
-                                    if !new_cookie.is_empty() {
-                                        account.cookie = Some(new_cookie);
-                                    }
-                                }
-                                self.data.save();
-                                self.util_new_password.clear();
-                                self.set_status(format!(" Password changed for {}", username), false);
-                            }
-                            Err(e) => self.set_status(format!("Failed: {}", e), true),
+                let username = self.data.accounts.get(idx).map(|a| a.username.clone()).unwrap_or_default();
+                self.set_status(format!("Changing password for {}...", username), false);
+                
+                let typed_current = self.util_current_password.clone();
+                match self.change_account_password(idx, &typed_current, new_pass) {
+                    Ok(cookie_rotated) => {
+                        self.util_new_password.clear();
+                        self.util_current_password.clear();
+                        let note = if cookie_rotated { "" } else { " (no new cookie returned, re-login may be needed)" };
+                        self.set_status(format!(" Password changed for {}{}", username, note), false);
+                    }
+                    Err(e) => self.set_status(format!("Failed: {}", e), true),
+                }
+            }
+            
+            Action::RotateSelectedPasswords => {
+                if self.password_rotation.is_some() {
+                    self.set_status("Passwords are already being rotated", true);
+                    return;
+                }
+                
+                let mut indices: Vec<usize> = self.batch_selected.iter().copied().collect();
+                indices.sort_unstable();
+                
+                self.password_rotation_report.clear();
+                let mut jobs = Vec::new();
+                for idx in indices {
+                    let username = self.data.accounts.get(idx).map(|a| a.username.clone()).unwrap_or_default();
+                    let new_pass = crate::auth::generate_password(20);
+                    match self.begin_password_change(idx, "", &new_pass) {
+                        Ok((cookie, current)) => jobs.push((username, cookie, current, new_pass)),
+                        Err(e) => self.password_rotation_report.push((username, Some(e))),
+                    }
+                }
+                
+                self.set_status(format!("Rotating passwords for {} account(s)...", jobs.len()), false);
+                self.password_rotation = Some(BatchTask::spawn(jobs, |(username, cookie, current, new_pass): (String, String, String, String)| {
+                    let result = RobloxApi::change_password(&cookie, &current, &new_pass);
+                    (username, new_pass, result)
+                }));
+                self.check_password_rotation();
+            }
+            
+            Action::ResolvePendingPassword(idx, use_pending) => {
+                if let Some(account) = self.data.accounts.get_mut(idx) {
+                    if let Some(pending) = account.pending_password.take() {
+                        if use_pending {
+                            account.password = pending;
                         }
+                        self.data.save();
                     }
                 }
             }
@@ -1134,6 +1541,9 @@ impl NexusApp {
             
             Action::BlockUserByName(idx, username) => {
                 let cookie = self.data.accounts.get(idx).and_then(|a| a.cookie.clone());
+                if let Some(user_id) = self.data.accounts.get(idx).and_then(|a| a.user_id) {
+                    self.blocked_users.remove(&user_id);
+                }
                 
                 if let Some(cookie) = cookie {
                     self.set_status(format!("Looking up {}...", username), false);
@@ -1153,6 +1563,9 @@ impl NexusApp {
             
             Action::UnblockUserByName(idx, username) => {
                 let cookie = self.data.accounts.get(idx).and_then(|a| a.cookie.clone());
+                if let Some(user_id) = self.data.accounts.get(idx).and_then(|a| a.user_id) {
+                    self.blocked_users.remove(&user_id);
+                }
                 
                 if let Some(cookie) = cookie {
                     self.set_status(format!("Looking up {}...", username), false);
@@ -1170,95 +1583,275 @@ impl NexusApp {
                 }
             }
             
-            Action::SendFriendRequestByName(idx, username) => {
-                let cookie = self.data.accounts.get(idx).and_then(|a| a.cookie.clone());
+            Action::FetchBlockedUsers(idx) => {
+                let account_data = self.data.accounts.get(idx).map(|a| (a.cookie.clone(), a.user_id));
                 
-                if let Some(cookie) = cookie {
-                    self.set_status(format!("Looking up {}...", username), false);
-                    
-                    match RobloxApi::get_user_id_by_username(&username) {
-                        Ok(target_id) => {
-                            self.set_status("Sending friend request...", false);
-                            match RobloxApi::send_friend_request(&cookie, target_id) {
-                                Ok(_) => self.set_status(" Friend request sent", false),
-                                Err(e) => self.set_status(format!("Failed: {}", e), true),
-                            }
+                if let Some((Some(cookie), Some(user_id))) = account_data {
+                    match RobloxApi::get_blocked_users(&cookie) {
+                        Ok(blocked) => {
+                            self.set_status(format!("[OK] {} blocked user(s)", blocked.len()), false);
+                            self.blocked_users.insert(user_id, blocked);
                         }
-                        Err(e) => self.set_status(format!("User not found: {}", e), true),
+                        Err(e) => self.set_status(format!("Failed to load blocked users: {}", e), true),
                     }
+                } else {
+                    self.set_status("Verify account first", true);
                 }
             }
             
-            Action::StartBrowserLogin => {
-                // Collect existing user IDs to avoid re-importing same accounts
-                let existing_ids: HashSet<u64> = self.data.accounts
-                    .iter()
-                    .filter_map(|a| a.user_id)
-                    .collect();
+            Action::UnblockUser(idx, target_id) => {
+                let Some((Some(cookie), Some(user_id))) = self.data.accounts.get(idx).map(|a| (a.cookie.clone(), a.user_id)) else {
+                    return;
+                };
                 
-                match crate::auth::WebLoginSession::start_with_existing_users(existing_ids) {
-                    Ok(session) => {
-                        self.set_status("Browser login started. Log in and click Play on any game...", false);
-                        self.browser_login_session = Some(session);
-                    }
-                    Err(e) => {
-                        self.set_status(format!("Failed to start browser login: {}", e), true);
+                match RobloxApi::unblock_user(&cookie, target_id) {
+                    Ok(()) => {
+                        if let Some(blocked) = self.blocked_users.get_mut(&user_id) {
+                            blocked.retain(|u| u.user_id != target_id);
+                        }
+                        self.set_status(" User unblocked", false);
                     }
+                    Err(e) => self.set_status(format!("Failed: {}", e), true),
                 }
             }
             
-            Action::ImportCookieAsNewAccount => {
-                let cookie = self.import_cookie.trim().to_string();
-                if cookie.is_empty() {
-                    self.set_status("Paste a cookie first", true);
+            Action::CopyBlockList(from_idx, to_idx) => {
+                let source = self.data.accounts.get(from_idx).map(|a| (a.cookie.clone(), a.user_id));
+                let target = self.data.accounts.get(to_idx).map(|a| (a.username.clone(), a.cookie.clone(), a.user_id));
+                
+                let (Some((Some(source_cookie), Some(source_id))), Some((target_name, Some(target_cookie), Some(target_id)))) = (source, target) else {
+                    self.set_status("Both accounts need a valid cookie", true);
+                    return;
+                };
+                if self.block_copy.is_some() {
+                    self.set_status("Already copying a block list", true);
                     return;
                 }
                 
-                self.set_status("Validating cookie...", false);
-                
-                match RobloxApi::validate_cookie(&cookie) {
-                    Ok((user_id, display_name)) => {
-                        // Check if account already exists
-                        if self.data.accounts.iter().any(|a| a.user_id == Some(user_id)) {
-                            self.set_status("Account already exists!", true);
+                let source_list = match self.blocked_users.get(&source_id) {
+                    Some(list) => list.clone(),
+                    None => match RobloxApi::get_blocked_users(&source_cookie) {
+                        Ok(list) => list,
+                        Err(e) => {
+                            self.set_status(format!("Failed to load block list: {}", e), true);
                             return;
                         }
-                        
-                        // Get the username
-                        let username = crate::api::RobloxApi::get_username_by_id(user_id)
-                            .unwrap_or_else(|_| display_name.clone());
-                        
-                        let account = RobloxAccount {
-                            username: username.clone(),
-                            password: String::new(),
-                            cookie: Some(cookie),
-                            user_id: Some(user_id),
-                            display_name: Some(display_name),
-                            last_login: Some(chrono::Local::now().format("%Y-%m-%d %H:%M").to_string()),
-                            status: AccountStatus::Valid,
-                            notes: String::new(),
-                            group: String::new(),
-                            robux: None,
-                            friends_count: None,
-                            is_premium: None,
-                            collectibles_count: None,
-                            last_info_fetch: None,
-                            presence: None,
-                            avatar_url: None,
-                        };
-                        
-                        self.data.accounts.push(account);
-                        self.data.save();
-                        self.set_status(format!(" Added: {} via cookie import", username), false);
-                        self.import_cookie.clear();
-                        self.tab = Tab::Accounts;
-                        
-                        // Fetch presence and avatars for the new account
-                        self.fetch_presence_and_avatars();
-                    }
+                    },
+                };
+                
+                let already: HashSet<u64> = match RobloxApi::get_blocked_users(&target_cookie) {
+                    Ok(list) => list.iter().map(|u| u.user_id).collect(),
                     Err(e) => {
-                        self.set_status(format!("Invalid cookie: {}", e), true);
+                        self.set_status(format!("Failed to load {}'s block list: {}", target_name, e), true);
+                        return;
+                    }
+                };
+                
+                let to_block: Vec<u64> = source_list.iter()
+                    .map(|u| u.user_id)
+                    .filter(|id| !already.contains(id))
+                    .collect();
+                let already = source_list.len() - to_block.len();
+                let task = BatchTask::spawn(to_block, move |user_id| RobloxApi::block_user(&target_cookie, user_id));
+                self.block_copy = Some(BlockCopyRun { task, target_name, target_id, already, blocked: 0, failed: 0 });
+                self.check_block_copy();
+            }
+            
+            Action::FetchPrivacySettings(idx) => {
+                let account_data = self.data.accounts.get(idx).map(|a| (a.cookie.clone(), a.user_id));
+                
+                if let Some((Some(cookie), Some(user_id))) = account_data {
+                    match RobloxApi::get_privacy_settings(&cookie) {
+                        Ok(values) => {
+                            self.set_status(format!("[OK] Loaded {} privacy setting(s)", values.len()), false);
+                            self.privacy_draft = values.clone();
+                            self.privacy_draft_account = Some(user_id);
+                            self.privacy_settings.insert(user_id, values);
+                        }
+                        Err(e) => self.set_status(format!("Failed to load privacy settings: {}", e), true),
                     }
+                } else {
+                    self.set_status("Verify account first", true);
+                }
+            }
+            
+            Action::ApplyPrivacySettings(idx) => {
+                let username = self.data.accounts.get(idx).map(|a| a.username.clone()).unwrap_or_default();
+                match self.apply_privacy_draft(idx, true) {
+                    Ok(0) => self.set_status("No privacy changes to apply", false),
+                    Ok(count) => self.set_status(format!("[OK] Updated {} privacy setting(s) for {}", count, username), false),
+                    Err(e) => self.set_status(format!("{}: {}", username, e), true),
+                }
+            }
+            
+            Action::ApplyPrivacyToSelected => {
+                if self.privacy_draft.is_empty() {
+                    self.set_status("Load an account's privacy settings first", true);
+                    return;
+                }
+                
+                if self.privacy_run.is_some() {
+                    self.set_status("Privacy settings are already being applied", true);
+                    return;
+                }
+                
+                let mut indices: Vec<usize> = self.batch_selected.iter().copied().collect();
+                indices.sort_unstable();
+                
+                let mut jobs = Vec::new();
+                let mut failures = Vec::new();
+                for idx in &indices {
+                    let Some(account) = self.data.accounts.get(*idx) else { continue };
+                    match (account.cookie.clone(), account.user_id) {
+                        (Some(cookie), Some(user_id)) => {
+                            jobs.push((account.username.clone(), user_id, cookie, self.privacy_changes(user_id, false)));
+                        }
+                        _ => failures.push(format!("{} (no valid cookie)", account.username)),
+                    }
+                }
+                
+                self.set_status(format!("Applying privacy settings to {} account(s)...", jobs.len()), false);
+                let task = BatchTask::spawn(jobs, |(username, user_id, cookie, changes): (String, u64, String, Vec<(&'static PrivacySetting, String)>)| {
+                    (username, user_id, Self::send_privacy_settings(&cookie, changes))
+                });
+                self.privacy_run = Some(PrivacyRun { task, total: indices.len(), applied: 0, failures });
+                self.check_privacy_run();
+            }
+            
+            Action::SendFriendRequestByName(idx, username) => {
+                let cookie = self.data.accounts.get(idx).and_then(|a| a.cookie.clone());
+                
+                if let Some(cookie) = cookie {
+                    self.set_status(format!("Looking up {}...", username), false);
+                    
+                    match RobloxApi::get_user_id_by_username(&username) {
+                        Ok(target_id) => {
+                            self.set_status("Sending friend request...", false);
+                            match RobloxApi::send_friend_request(&cookie, target_id) {
+                                Ok(_) => self.set_status(" Friend request sent", false),
+                                Err(e) => self.set_status(format!("Failed: {}", e), true),
+                            }
+                        }
+                        Err(e) => self.set_status(format!("User not found: {}", e), true),
+                    }
+                }
+            }
+            
+            Action::StartBrowserLogin => {
+                // Fresh throwaway profile so an earlier login can't leak into this one
+                let options = crate::auth::BrowserOptions::new(&self.data.browser_path, crate::auth::BrowserProfile::Temporary);
+                
+                match crate::auth::WebLoginSession::start_new(options) {
+                    Ok(session) => {
+                        if self.login_queue_enabled && self.login_queue_added > 0 {
+                            self.set_status(format!("Login queue: {} added - log in to the next account...", self.login_queue_added), false);
+                        } else {
+                            self.set_status("Browser login started. Log in and click Play on any game...", false);
+                        }
+                        self.browser_login_session = Some(session);
+                        self.browser_login_status = Some(crate::auth::BrowserStatus::Launching);
+                        self.relogin_account = None;
+                    }
+                    Err(e) => {
+                        self.set_status(format!("Failed to start browser login: {}", e), true);
+                    }
+                }
+            }
+            
+            Action::ReloginAccount(idx) => {
+                if self.browser_login_session.is_some() {
+                    self.set_status("A browser login is already in progress", true);
+                    return;
+                }
+                
+                let account_data = self.data.accounts.get(idx).map(|a| {
+                    (a.username.clone(), a.password.clone(), a.user_id, AccountRef::of(a))
+                });
+                
+                if let Some((username, password, user_id, target)) = account_data {
+                    // Both flows use the account's profile, which Chromium locks while it's open
+                    if user_id.is_some_and(|id| self.account_browsers.contains_key(&id)) {
+                        self.set_status(format!("Close {}'s browser before logging in again", username), true);
+                        return;
+                    }
+                    
+                    let profile = match user_id {
+                        Some(id) => crate::auth::BrowserProfile::Account(id),
+                        None => crate::auth::BrowserProfile::Temporary,
+                    };
+                    let options = crate::auth::BrowserOptions::new(&self.data.browser_path, profile);
+                    
+                    match crate::auth::WebLoginSession::start_relogin(options, &username, &password) {
+                        Ok(session) => {
+                            self.set_status(format!("Re-login started for {} - complete any captcha in the browser...", username), false);
+                            self.browser_login_session = Some(session);
+                            self.browser_login_status = Some(crate::auth::BrowserStatus::Launching);
+                            self.relogin_account = Some(target);
+                        }
+                        Err(e) => {
+                            self.set_status(format!("Failed to start browser login: {}", e), true);
+                        }
+                    }
+                }
+            }
+            
+            Action::OpenAccountBrowser(idx) => {
+                let account_data = self.data.accounts.get(idx).map(|a| {
+                    (a.username.clone(), a.cookie.clone(), a.user_id)
+                });
+                
+                if let Some((username, cookie, user_id)) = account_data {
+                    let (Some(cookie), Some(user_id)) = (cookie, user_id) else {
+                        self.set_status(format!("Verify {} before opening it in the browser", username), true);
+                        return;
+                    };
+                    
+                    if self.account_browsers.contains_key(&user_id) {
+                        self.set_status(format!("A browser for {} is already open", username), true);
+                        return;
+                    }
+                    if self.browser_login_session.is_some() && self.relogin_idx() == Some(idx) {
+                        self.set_status(format!("Finish re-logging in {} first", username), true);
+                        return;
+                    }
+                    
+                    let options = crate::auth::BrowserOptions::new(&self.data.browser_path, crate::auth::BrowserProfile::Account(user_id));
+                    match crate::auth::AccountBrowserSession::open(options, &cookie) {
+                        Ok(session) => {
+                            self.account_browsers.insert(user_id, session);
+                            self.set_status(format!("Opening roblox.com as {}...", username), false);
+                        }
+                        Err(e) => {
+                            self.set_status(format!("Failed to open browser: {}", e), true);
+                        }
+                    }
+                }
+            }
+            
+            Action::CloseAccountBrowser(idx) => {
+                let user_id = self.data.accounts.get(idx).and_then(|a| a.user_id);
+                if let Some(session) = user_id.and_then(|id| self.account_browsers.get(&id)) {
+                    session.close();
+                }
+            }
+            
+            Action::ImportCookieAsNewAccount => {
+                if self.import_cookie.trim().is_empty() {
+                    self.set_status("Paste a cookie first", true);
+                    return;
+                }
+                
+                let cookies = crate::auth::parse_cookies(&self.import_cookie);
+                if cookies.is_empty() {
+                    self.set_status("No .ROBLOSECURITY cookie found in the pasted text", true);
+                    return;
+                }
+                
+                self.set_status("Validating cookie...", false);
+                
+                if self.import_cookies_as_accounts(cookies, "cookie import") {
+                    self.import_cookie.clear();
+                    self.tab = Tab::Accounts;
                 }
             }
             
@@ -1271,12 +1864,16 @@ impl NexusApp {
             }
             
             Action::UpdateAccountCookie(idx) => {
-                let new_cookie = self.cookie_modal_value.trim().to_string();
-                if new_cookie.is_empty() {
+                if self.cookie_modal_value.trim().is_empty() {
                     self.set_status("Cookie cannot be empty", true);
                     return;
                 }
                 
+                let Some(new_cookie) = crate::auth::parse_cookie(&self.cookie_modal_value) else {
+                    self.set_status("No .ROBLOSECURITY cookie found in the pasted text", true);
+                    return;
+                };
+                
                 // Validate the new cookie
                 match RobloxApi::validate_cookie(&new_cookie) {
                     Ok((user_id, display_name)) => {
@@ -1329,7 +1926,7 @@ impl NexusApp {
                     
                     // Get target user's presence
                     match RobloxApi::get_user_presence_by_username(&target_username) {
-                        Ok((_, presence)) => {
+                        Ok((target_id, presence)) => {
                             use crate::account::UserPresenceType;
                             
                             match presence.presence_type {
@@ -1343,17 +1940,7 @@ impl NexusApp {
                                         
                                         self.set_status(format!("⏳ Joining {} - please wait...", game_name), false);
                                         
-                                        let result = if let Some(ref job_id) = presence.game_id {
-                                            RobloxApi::launch_to_server(&cookie, &place_id.to_string(), job_id)
-                                        } else {
-                                            if self.multi_instance.is_enabled() {
-                                                RobloxApi::launch_multi_instance(&cookie, Some(&place_id.to_string()))
-                                            } else {
-                                                RobloxApi::set_account_and_launch(&cookie, Some(&place_id.to_string()))
-                                            }
-                                        };
-                                        
-                                        match result {
+                                        match self.launch_into_game(&account_name, &cookie, target_id, place_id, presence.game_id.as_deref()) {
                                             Ok(_) => {
                                                 self.set_status(format!("✅ {} following {} into {} - Roblox is starting up", account_name, target_username, game_name), false);
                                                 self.follow_user_show = false;
@@ -1386,69 +1973,139 @@ impl NexusApp {
                 }
             }
             
-            Action::ImportDroppedCookie(cookie) => {
-                // Validate and import the dropped cookie
-                let cookie = cookie.trim().to_string();
-                if cookie.is_empty() {
-                    self.set_status("Dropped content is empty", true);
-                    return;
+            Action::FetchFriends(idx) => {
+                let account_data = self.data.accounts.get(idx).map(|a| (a.username.clone(), a.cookie.clone(), a.user_id));
+                
+                if let Some((username, Some(cookie), Some(user_id))) = account_data {
+                    self.set_status(format!("Loading friends of {}...", username), false);
+                    
+                    match RobloxApi::get_friends(&cookie, user_id) {
+                        Ok(friends) => {
+                            let online = friends.iter()
+                                .filter(|f| f.presence.as_ref().is_some_and(|p| p.presence_type != crate::account::UserPresenceType::Offline))
+                                .count();
+                            self.set_status(format!("[OK] {} friends, {} online", friends.len(), online), false);
+                            self.friends.insert(user_id, friends);
+                        }
+                        Err(e) => self.set_status(format!("Failed to load friends: {}", e), true),
+                    }
+                } else {
+                    self.set_status("Verify account first", true);
                 }
+            }
+            
+            Action::JoinFriend(idx, friend_id) => {
+                let account_data = self.data.accounts.get(idx).map(|a| (a.username.clone(), a.cookie.clone(), a.user_id));
                 
-                // Check if it looks like a cookie
-                if !cookie.contains("_|WARNING:-DO-NOT-SHARE") && cookie.len() < 100 {
-                    self.set_status("Dropped content doesn't look like a valid cookie", true);
+                let Some((username, Some(cookie), Some(user_id))) = account_data else {
+                    self.set_status("Account needs a valid cookie", true);
                     return;
-                }
+                };
                 
-                self.set_status("Validating dropped cookie...", false);
+                let friend = self.friends.get(&user_id)
+                    .and_then(|list| list.iter().find(|f| f.user_id == friend_id))
+                    .cloned();
+                let Some(friend) = friend else {
+                    return;
+                };
+                let Some(place_id) = friend.presence.as_ref().and_then(|p| p.place_id) else {
+                    self.set_status(format!("{} is not in a joinable game", friend.username), true);
+                    return;
+                };
+                let job_id = friend.presence.as_ref().and_then(|p| p.game_id.clone());
                 
-                match RobloxApi::validate_cookie(&cookie) {
-                    Ok((user_id, display_name)) => {
-                        // Check if account already exists
-                        if self.data.accounts.iter().any(|a| a.user_id == Some(user_id)) {
-                            self.set_status("Account already exists!", true);
-                            return;
+                self.set_status(format!("⏳ Joining {} - please wait...", friend.username), false);
+                match self.launch_into_game(&username, &cookie, friend_id, place_id, job_id.as_deref()) {
+                    Ok(_) => self.set_status(format!("✅ {} joining {} - Roblox is starting up", username, friend.username), false),
+                    Err(e) => self.set_status(format!("Launch failed: {}", e), true),
+                }
+            }
+            
+            Action::FetchFriendRequests(idx) => {
+                let account_data = self.data.accounts.get(idx).map(|a| (a.cookie.clone(), a.user_id));
+                
+                if let Some((Some(cookie), Some(user_id))) = account_data {
+                    match RobloxApi::get_friend_requests(&cookie) {
+                        Ok(requests) => {
+                            self.set_status(format!("[OK] {} pending friend request(s)", requests.len()), false);
+                            self.set_pending_friend_requests(idx, requests.len());
+                            self.friend_requests.insert(user_id, requests);
                         }
-                        
-                        // Get the username
-                        let username = RobloxApi::get_username_by_id(user_id)
-                            .unwrap_or_else(|_| display_name.clone());
-                        
-                        let account = RobloxAccount {
-                            username: username.clone(),
-                            password: String::new(),
-                            cookie: Some(cookie),
-                            user_id: Some(user_id),
-                            display_name: Some(display_name),
-                            last_login: Some(chrono::Local::now().format("%Y-%m-%d %H:%M").to_string()),
-                            status: AccountStatus::Valid,
-                            notes: String::new(),
-                            group: String::new(),
-                            robux: None,
-                            friends_count: None,
-                            is_premium: None,
-                            collectibles_count: None,
-                            last_info_fetch: None,
-                            presence: None,
-                            avatar_url: None,
-                        };
-                        
-                        self.data.accounts.push(account);
-                        self.data.save();
-                        self.set_status(format!("✅ Added: {} via drag & drop", username), false);
-                        self.tab = Tab::Accounts;
-                        
-                        // Fetch presence and avatars for the new account
-                        self.fetch_presence_and_avatars();
+                        Err(e) => self.set_status(format!("Failed to load friend requests: {}", e), true),
                     }
-                    Err(e) => {
-                        self.set_status(format!("Invalid cookie: {}", e), true);
+                } else {
+                    self.set_status("Verify account first", true);
+                }
+            }
+            
+            Action::AcceptFriendRequest(idx, sender_id) => {
+                let Some((Some(cookie), Some(user_id))) = self.data.accounts.get(idx).map(|a| (a.cookie.clone(), a.user_id)) else {
+                    return;
+                };
+                
+                match RobloxApi::accept_friend_request(&cookie, sender_id) {
+                    Ok(()) => {
+                        let name = self.remove_friend_request(idx, user_id, sender_id);
+                        // The cached friends list is stale now
+                        self.friends.remove(&user_id);
+                        self.set_status(format!("[OK] Accepted friend request from {}", name), false);
+                    }
+                    Err(e) => self.set_status(format!("Failed to accept: {}", e), true),
+                }
+            }
+            
+            Action::DeclineFriendRequest(idx, sender_id) => {
+                let Some((Some(cookie), Some(user_id))) = self.data.accounts.get(idx).map(|a| (a.cookie.clone(), a.user_id)) else {
+                    return;
+                };
+                
+                match RobloxApi::decline_friend_request(&cookie, sender_id) {
+                    Ok(()) => {
+                        let name = self.remove_friend_request(idx, user_id, sender_id);
+                        self.set_status(format!("Declined friend request from {}", name), false);
                     }
+                    Err(e) => self.set_status(format!("Failed to decline: {}", e), true),
+                }
+            }
+            
+            Action::DeclineAllFriendRequests(idx) => {
+                let Some((Some(cookie), Some(user_id))) = self.data.accounts.get(idx).map(|a| (a.cookie.clone(), a.user_id)) else {
+                    return;
+                };
+                
+                match RobloxApi::decline_all_friend_requests(&cookie) {
+                    Ok(()) => {
+                        self.friend_requests.insert(user_id, Vec::new());
+                        self.set_pending_friend_requests(idx, 0);
+                        self.set_status("[OK] Declined all friend requests", false);
+                    }
+                    Err(e) => self.set_status(format!("Failed to decline all: {}", e), true),
+                }
+            }
+            
+            Action::ImportDroppedCookie(content) => {
+                let cookies = crate::auth::parse_cookies(&content);
+                if cookies.is_empty() {
+                    self.set_status("Dropped content doesn't contain a .ROBLOSECURITY cookie", true);
+                    self.drag_drop_active = false;
+                    return;
+                }
+                
+                self.set_status("Validating dropped cookie...", false);
+                
+                if self.import_cookies_as_accounts(cookies, "drag & drop") {
+                    self.tab = Tab::Accounts;
                 }
                 
                 self.drag_drop_active = false;
             }
             
+            Action::OpenLink(target) => {
+                self.drag_drop_active = false;
+                self.apply_link_target(target);
+                self.tab = Tab::Accounts;
+            }
+            
             Action::AddUserGame(place_id, name) => {
                 // Check if game already exists
                 if self.data.user_games.iter().any(|g| g.place_id == place_id) {
@@ -1520,7 +2177,7 @@ impl NexusApp {
                             }
                         }
                         
-                        account.presence = Some(p);
+                        account.set_presence(p);
                     }
                 }
             }
@@ -1539,79 +2196,870 @@ impl NexusApp {
             }
         }
         
-        // Fetch avatars
-        if let Ok(avatars) = RobloxApi::get_avatar_thumbnails(&user_ids) {
-            for account in self.data.accounts.iter_mut() {
-                if let Some(user_id) = account.user_id {
-                    if let Some(url) = avatars.get(&user_id) {
-                        account.avatar_url = Some(url.clone());
-                    }
-                }
-            }
-            self.data.save();
+        // Fetch avatars
+        if let Ok(avatars) = RobloxApi::get_avatar_thumbnails(&user_ids) {
+            for account in self.data.accounts.iter_mut() {
+                if let Some(user_id) = account.user_id {
+                    if let Some(url) = avatars.get(&user_id) {
+                        account.avatar_url = Some(url.clone());
+                    }
+                }
+            }
+            self.data.save();
+        }
+        
+        self.last_presence_refresh = std::time::Instant::now();
+    }
+    
+    pub fn refresh_presence_only(&mut self) {
+        let user_ids: Vec<u64> = self.data.accounts.iter()
+            .filter_map(|a| {
+                if a.status == crate::account::AccountStatus::Valid {
+                    a.user_id
+                } else {
+                    None
+                }
+            })
+            .collect();
+        
+        if user_ids.is_empty() {
+            return;
+        }
+        
+        if let Ok(presences) = RobloxApi::get_presence(&user_ids) {
+            let mut place_ids_to_lookup: std::collections::HashMap<u64, Vec<usize>> = std::collections::HashMap::new();
+            
+            for (idx, account) in self.data.accounts.iter_mut().enumerate() {
+                if let Some(user_id) = account.user_id {
+                    if let Some(presence) = presences.get(&user_id) {
+                        let p = presence.clone();
+                        
+                        if p.presence_type == crate::account::UserPresenceType::InGame {
+                            let needs_lookup = p.last_location.as_ref().map(|s| s.is_empty()).unwrap_or(true);
+                            if needs_lookup {
+                                if let Some(place_id) = p.place_id {
+                                    place_ids_to_lookup.entry(place_id).or_default().push(idx);
+                                }
+                            }
+                        }
+                        
+                        account.set_presence(p);
+                    }
+                }
+            }
+            
+            for (place_id, account_indices) in place_ids_to_lookup {
+                if let Ok((game_name, _)) = RobloxApi::get_game_info(&place_id.to_string()) {
+                    for idx in account_indices {
+                        if let Some(account) = self.data.accounts.get_mut(idx) {
+                            if let Some(ref mut presence) = account.presence {
+                                presence.game_name = Some(game_name.clone());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        
+        self.last_presence_refresh = std::time::Instant::now();
+    }
+    
+    /// Fetch robux, friends, premium and profile details for one account. Blocks, so it
+    /// runs on a `BatchTask`.
+    fn request_account_info(cookie: &str, user_id: u64) -> Result<AccountFetch, String> {
+        let info = RobloxApi::get_account_info(cookie, user_id)
+            .map_err(|e| format!("Failed to fetch info: {}", e))?;
+        let details = RobloxApi::get_account_details(user_id).ok();
+        // A private inventory errors out; leave the count unknown rather than zero
+        let collectibles = RobloxApi::get_collectibles(user_id).ok();
+        Ok(AccountFetch { info, details, collectibles })
+    }
+    
+    fn apply_account_info(&mut self, idx: usize, fetch: AccountFetch) {
+        let AccountFetch { info, details, collectibles } = fetch;
+        let now = chrono::Local::now().format("%Y-%m-%d %H:%M").to_string();
+        
+        if let Some(account) = self.data.accounts.get_mut(idx) {
+            // A failed balance check isn't a drop to zero
+            if let Some(robux) = info.robux {
+                account.record_robux(robux, now.clone());
+            }
+            account.friends_count = Some(info.friends_count);
+            account.is_premium = Some(info.is_premium);
+            account.pending_friend_requests = Some(info.friend_requests);
+            if let Some(mut details) = details {
+                details.collectibles_count = collectibles.as_ref().map(|items| items.len() as u32);
+                account.details = Some(details);
+            }
+            if let Some(items) = collectibles {
+                account.collectibles_count = Some(items.len() as u32);
+                account.collectibles = items;
+                let snapshot = ValueSnapshot {
+                    time: now.clone(),
+                    total_rap: account.inventory_value(),
+                    item_count: account.collectibles.len() as u32,
+                };
+                push_value_snapshot(&mut account.value_history, snapshot);
+            }
+            account.last_info_fetch = Some(now);
+        }
+    }
+    
+    /// Store single-account refreshes that have finished
+    pub fn check_account_fetches(&mut self) {
+        let mut finished = Vec::new();
+        for (user_id, task) in self.account_fetches.iter_mut() {
+            let result = task.poll().pop();
+            if result.is_some() || task.is_finished() {
+                finished.push((*user_id, result));
+            }
+        }
+        
+        for (user_id, result) in finished {
+            self.account_fetches.remove(&user_id);
+            // The account may have moved or been removed meanwhile
+            let Some(idx) = self.data.accounts.iter().position(|a| a.user_id == Some(user_id)) else { continue };
+            let username = self.data.accounts[idx].username.clone();
+            match result {
+                Some(Ok(fetch)) => {
+                    self.apply_account_info(idx, fetch);
+                    self.data.save();
+                    match self.robux_drop_warning(idx) {
+                        Some(warning) => self.set_status(warning, true),
+                        None => self.set_status(format!("[OK] Updated info for {}", username), false),
+                    }
+                }
+                Some(Err(e)) => self.set_status(e, true),
+                None => self.set_status(format!("Fetching info for {} stopped", username), true),
+            }
+        }
+    }
+    
+    /// Store results from a running "fetch all" pass, and report once it's done
+    pub fn check_info_fetch(&mut self) {
+        let Some(mut run) = self.info_fetch.take() else { return };
+        
+        for (user_id, result) in run.task.poll() {
+            // Look the account up again, it may have moved or been removed meanwhile
+            let Some(idx) = self.data.accounts.iter().position(|a| a.user_id == Some(user_id)) else { continue };
+            match result {
+                Ok(fetch) => {
+                    self.apply_account_info(idx, fetch);
+                    run.updated += 1;
+                    run.warnings.extend(self.robux_drop_warning(idx));
+                }
+                Err(_) => run.failed += 1,
+            }
+        }
+        
+        if !run.task.is_finished() {
+            let (done, total) = run.task.progress();
+            self.set_status(format!("Fetching account info ({}/{})...", done, total), false);
+            self.info_fetch = Some(run);
+            return;
+        }
+        
+        // Only a full pass gives a total worth charting
+        let (total_rap, item_count) = self.data.total_inventory_value();
+        let time = chrono::Local::now().format("%Y-%m-%d %H:%M").to_string();
+        push_value_snapshot(&mut self.data.value_history, ValueSnapshot { time, total_rap, item_count });
+        
+        self.data.save();
+        if run.warnings.is_empty() {
+            self.set_status(
+                format!("Fetched info for {} account(s), {} cached, {} failed", run.updated, run.skipped, run.failed),
+                run.failed > 0,
+            );
+        } else {
+            self.set_status(run.warnings.join(" | "), true);
+        }
+    }
+    
+    /// Change one account's password, using the stored password as the current one
+    /// (or `typed_current` when none is stored). The new password is saved as pending
+    /// before the request goes out, so it survives a crash or a lost response.
+    /// Returns whether Roblox handed back a rotated cookie.
+    fn change_account_password(&mut self, idx: usize, typed_current: &str, new_password: String) -> Result<bool, String> {
+        let (cookie, current) = self.begin_password_change(idx, typed_current, &new_password)?;
+        let result = RobloxApi::change_password(&cookie, &current, &new_password);
+        self.finish_password_change(idx, new_password, result)
+    }
+    
+    /// Check a password change can go ahead and save the new password as pending.
+    /// Returns the cookie and current password to send to Roblox.
+    fn begin_password_change(&mut self, idx: usize, typed_current: &str, new_password: &str) -> Result<(String, String), String> {
+        let Some(account) = self.data.accounts.get_mut(idx) else {
+            return Err("Account not found".to_string());
+        };
+        let Some(cookie) = account.cookie.clone() else {
+            return Err("no cookie".to_string());
+        };
+        if account.pending_password.is_some() {
+            return Err("an earlier change was interrupted; resolve it first".to_string());
+        }
+        let current = if account.password.is_empty() { typed_current.to_string() } else { account.password.clone() };
+        if current.is_empty() {
+            return Err("no stored password".to_string());
+        }
+        
+        account.pending_password = Some(new_password.to_string());
+        self.data.save();
+        Ok((cookie, current))
+    }
+    
+    /// Store Roblox's answer to a change started with `begin_password_change`
+    fn finish_password_change(&mut self, idx: usize, new_password: String, result: Result<String, String>) -> Result<bool, String> {
+        let Some(account) = self.data.accounts.get_mut(idx) else {
+            return Err("Account not found".to_string());
+        };
+        let outcome = match result {
+            Ok(new_cookie) => {
+                account.password = new_password;
+                account.pending_password = None;
+                let rotated = !new_cookie.is_empty();
+                if rotated {
+                    account.cookie = Some(new_cookie);
+                }
+                Ok(rotated)
+            }
+            // No response means the change may or may not have happened; keep it pending
+            Err(e) if e.starts_with("Request failed") => Err(format!("{} (new password kept as pending)", e)),
+            Err(e) => {
+                account.pending_password = None;
+                Err(e)
+            }
+        };
+        self.data.save();
+        outcome
+    }
+    
+    /// Write the Privacy draft to one account, optionally only the values that differ
+    /// from what was last read for it. Returns how many settings were changed.
+    fn apply_privacy_draft(&mut self, idx: usize, only_changed: bool) -> Result<usize, String> {
+        let Some((Some(cookie), Some(user_id))) = self.data.accounts.get(idx).map(|a| (a.cookie.clone(), a.user_id)) else {
+            return Err("no valid cookie".to_string());
+        };
+        
+        let outcome = Self::send_privacy_settings(&cookie, self.privacy_changes(user_id, only_changed));
+        let changed = outcome.applied.len();
+        self.record_privacy(user_id, outcome.applied);
+        
+        if outcome.errors.is_empty() {
+            Ok(changed)
+        } else {
+            Err(outcome.errors.join("; "))
+        }
+    }
+    
+    /// Draft settings to send to an account: all of them, or only the ones it doesn't have yet
+    fn privacy_changes(&self, user_id: u64, only_changed: bool) -> Vec<(&'static PrivacySetting, String)> {
+        let current = self.privacy_settings.get(&user_id);
+        crate::api::PRIVACY_SETTINGS.iter()
+            .filter_map(|setting| self.privacy_draft.get(setting.field).map(|value| (setting, value.clone())))
+            .filter(|(setting, value)| !only_changed || current.and_then(|c| c.get(setting.field)) != Some(value))
+            .collect()
+    }
+    
+    /// The blocking half of applying privacy settings, safe to run off the UI thread
+    fn send_privacy_settings(cookie: &str, changes: Vec<(&'static PrivacySetting, String)>) -> PrivacyOutcome {
+        let mut outcome = PrivacyOutcome { applied: Vec::new(), errors: Vec::new() };
+        for (setting, value) in changes {
+            match RobloxApi::set_privacy_setting(cookie, setting, &value) {
+                Ok(()) => outcome.applied.push((setting.field, value)),
+                Err(e) => outcome.errors.push(format!("{}: {}", setting.label, e)),
+            }
+        }
+        outcome
+    }
+    
+    fn record_privacy(&mut self, user_id: u64, applied: Vec<(&'static str, String)>) {
+        let settings = self.privacy_settings.entry(user_id).or_default();
+        for (field, value) in applied {
+            settings.insert(field.to_string(), value);
+        }
+    }
+    
+    /// Take in results from a running "apply to selected", and report once it's done
+    pub fn check_privacy_run(&mut self) {
+        let Some(mut run) = self.privacy_run.take() else { return };
+        
+        for (username, user_id, outcome) in run.task.poll() {
+            if outcome.errors.is_empty() {
+                run.applied += 1;
+            } else {
+                run.failures.push(format!("{} ({})", username, outcome.errors.join("; ")));
+            }
+            self.record_privacy(user_id, outcome.applied);
+        }
+        
+        if !run.task.is_finished() {
+            let (done, total) = run.task.progress();
+            self.set_status(format!("Applying privacy settings ({}/{})...", done, total), false);
+            self.privacy_run = Some(run);
+            return;
+        }
+        
+        if run.failures.is_empty() {
+            self.set_status(format!("[OK] Applied privacy settings to {} account(s)", run.applied), false);
+        } else {
+            self.set_status(
+                format!("Applied privacy settings to {} of {} account(s); failed: {}", run.applied, run.total, run.failures.join(", ")),
+                true,
+            );
+        }
+    }
+    
+    /// Count blocks from a running block list copy, and report once it's done
+    pub fn check_block_copy(&mut self) {
+        let Some(mut run) = self.block_copy.take() else { return };
+        
+        for result in run.task.poll() {
+            match result {
+                Ok(()) => run.blocked += 1,
+                Err(_) => run.failed += 1,
+            }
+        }
+        
+        if !run.task.is_finished() {
+            let (done, total) = run.task.progress();
+            self.set_status(format!("Copying block list to {} ({}/{})...", run.target_name, done, total), false);
+            self.block_copy = Some(run);
+            return;
+        }
+        
+        self.blocked_users.remove(&run.target_id);
+        self.set_status(
+            format!(
+                "Copied block list to {}: {} blocked, {} already blocked, {} failed",
+                run.target_name, run.blocked, run.already, run.failed
+            ),
+            run.failed > 0,
+        );
+    }
+    
+    /// Take in results from a running password rotation, and report once it's done
+    pub fn check_password_rotation(&mut self) {
+        let Some(mut task) = self.password_rotation.take() else { return };
+        
+        for (username, new_pass, result) in task.poll() {
+            // The pending password is unique to this change, so it finds the account even if the list changed
+            let idx = self.data.accounts.iter().position(|a| a.pending_password.as_deref() == Some(new_pass.as_str()));
+            let outcome = match idx.map(|idx| self.finish_password_change(idx, new_pass, result)) {
+                Some(Ok(true)) => None,
+                Some(Ok(false)) => Some("changed, but no new cookie returned; re-login needed".to_string()),
+                Some(Err(e)) => Some(e),
+                None => Some("account was removed".to_string()),
+            };
+            self.password_rotation_report.push((username, outcome));
+        }
+        
+        if !task.is_finished() {
+            let (done, total) = task.progress();
+            self.set_status(format!("Rotating passwords ({}/{})...", done, total), false);
+            self.password_rotation = Some(task);
+            return;
+        }
+        
+        let failed = self.password_rotation_report.iter().filter(|(_, e)| e.is_some()).count();
+        let total = self.password_rotation_report.len();
+        self.set_status(
+            format!("Rotated passwords for {} of {} account(s)", total - failed, total),
+            failed > 0,
+        );
+    }
+    
+    /// True while a background batch needs the UI to keep polling it
+    pub fn has_background_work(&self) -> bool {
+        self.cookie_scan.is_some() || self.info_fetch.is_some() || !self.account_fetches.is_empty()
+            || self.password_rotation.is_some() || self.privacy_run.is_some() || self.block_copy.is_some()
+    }
+    
+    fn set_pending_friend_requests(&mut self, idx: usize, count: usize) {
+        if let Some(account) = self.data.accounts.get_mut(idx) {
+            account.pending_friend_requests = Some(count as u32);
+            self.data.save();
+        }
+    }
+    
+    /// Drop a handled request from the inbox and return the sender's name
+    fn remove_friend_request(&mut self, idx: usize, user_id: u64, sender_id: u64) -> String {
+        let mut name = sender_id.to_string();
+        let mut remaining = 0;
+        if let Some(requests) = self.friend_requests.get_mut(&user_id) {
+            if let Some(pos) = requests.iter().position(|r| r.user_id == sender_id) {
+                name = requests.remove(pos).username;
+            }
+            remaining = requests.len();
+        }
+        self.set_pending_friend_requests(idx, remaining);
+        name
+    }
+    
+    /// Join `target_user_id`'s server, letting Roblox find it when their presence hides the job ID
+    fn launch_into_game(&self, username: &str, cookie: &str, target_user_id: u64, place_id: u64, job_id: Option<&str>) -> Result<(), String> {
+        let options = self.launch_options(username);
+        match job_id {
+            Some(job_id) => RobloxApi::launch_to_server(cookie, &place_id.to_string(), job_id, &options),
+            None => RobloxApi::launch(cookie, LaunchRequest::new(LaunchMode::FollowUser { user_id: target_user_id }), &options),
+        }
+    }
+    
+    pub fn launch_options(&self, username: &str) -> LaunchOptions {
+        let locale = self.data.accounts.iter()
+            .find(|a| a.username == username)
+            .map(|a| a.launch_defaults.locale.trim())
+            .filter(|l| !l.is_empty())
+            .unwrap_or(self.data.launch_locale.trim());
+        
+        LaunchOptions {
+            multi_instance: self.multi_instance.is_enabled(),
+            account: username.to_string(),
+            backend: self.data.launcher_backend.clone(),
+            locale: locale.to_string(),
+            channel: self.data.launch_channel.trim().to_string(),
+            platform: self.platform.clone(),
+            clients: self.client_tracker.clone(),
+        }
+    }
+    
+    /// Status message when the account's latest fetch shows a Robux drop above the alert threshold
+    fn robux_drop_warning(&self, idx: usize) -> Option<String> {
+        let account = self.data.accounts.get(idx)?;
+        let lost = account.latest_robux_drop(self.data.robux_drop_alert)?;
+        Some(format!("[!] {} lost R${} since the last check", account.username, lost))
+    }
+    
+    /// Validate each cookie and add it as a new account, skipping ones already managed.
+    /// Reports the outcome in the status bar and returns whether anything was added.
+    pub fn import_cookies_as_accounts(&mut self, cookies: Vec<String>, via: &str) -> bool {
+        let total = cookies.len();
+        let mut added = Vec::new();
+        let mut last_error = None;
+        
+        for cookie in cookies {
+            match RobloxApi::validate_cookie(&cookie) {
+                Ok((user_id, display_name)) => {
+                    // Check if account already exists
+                    if self.data.accounts.iter().any(|a| a.user_id == Some(user_id)) {
+                        last_error = Some("Account already exists!".to_string());
+                        continue;
+                    }
+                    
+                    // Get the username
+                    let username = RobloxApi::get_username_by_id(user_id)
+                        .unwrap_or_else(|_| display_name.clone());
+                    
+                    let account = RobloxAccount {
+                        username: username.clone(),
+                        password: String::new(),
+                        pending_password: None,
+                        cookie: Some(cookie),
+                        user_id: Some(user_id),
+                        display_name: Some(display_name),
+                        last_login: Some(chrono::Local::now().format("%Y-%m-%d %H:%M").to_string()),
+                        status: AccountStatus::Valid,
+                        notes: String::new(),
+                        group: String::new(),
+                        robux: None,
+                        friends_count: None,
+                        is_premium: None,
+                        collectibles_count: None,
+                        last_info_fetch: None,
+                        presence: None,
+                        avatar_url: None,
+                        details: None,
+                        collectibles: Vec::new(),
+                        value_history: Vec::new(),
+                        robux_history: Vec::new(),
+                        pending_friend_requests: None,
+                        launch_defaults: Default::default(),
+                        last_server: None,
+                    };
+                    
+                    self.data.accounts.push(account);
+                    added.push(username);
+                }
+                Err(e) => {
+                    last_error = Some(format!("Invalid cookie: {}", e));
+                }
+            }
+        }
+        
+        if added.is_empty() {
+            self.set_status(last_error.unwrap_or_else(|| "No cookies to import".to_string()), true);
+            return false;
+        }
+        
+        self.data.save();
+        if total == 1 {
+            self.set_status(format!("✅ Added: {} via {}", added[0], via), false);
+        } else {
+            self.set_status(format!("✅ Added {} of {} account(s) via {}: {}", added.len(), total, via, added.join(", ")), false);
+        }
+        
+        // Fetch presence and avatars for the new accounts
+        self.fetch_presence_and_avatars();
+        true
+    }
+    
+    /// Open a client for one account and report how it went
+    fn launch_with_mode(&mut self, username: &str, cookie: &str, mode: LaunchMode) {
+        let options = self.launch_options(username);
+        match RobloxApi::launch(cookie, LaunchRequest::new(mode.clone()), &options) {
+            Ok(_) => {
+                if let Some(place_id) = mode.place_id() {
+                    self.add_recent_game(&place_id.to_string());
+                }
+                self.set_status(format!("✅ Launched {} - Roblox is starting up", username), false);
+            }
+            Err(e) => self.set_status(format!("Launch failed: {}", e), true),
+        }
+    }
+    
+    /// Account a `nexus://` link names, by user ID or username
+    fn find_link_account(&self, account: &str) -> Option<usize> {
+        self.data.accounts.iter()
+            .position(|a| a.user_id.is_some_and(|id| id.to_string() == account))
+            .or_else(|| self.data.accounts.iter().position(|a| a.username.eq_ignore_ascii_case(account)))
+    }
+    
+    /// Take in sessions from a running cookie search, and report once it's finished
+    pub fn check_cookie_scan(&mut self) {
+        let Some(receiver) = self.cookie_scan.take() else { return };
+        loop {
+            match receiver.try_recv() {
+                Ok(ScanEvent::Found(cookies)) => {
+                    self.set_status(format!("Found {} cookie(s), checking...", cookies.len()), false);
+                    self.found_cookies = cookies;
+                }
+                Ok(ScanEvent::Checked(idx, cookie)) => {
+                    if let Some(slot) = self.found_cookies.get_mut(idx) {
+                        *slot = cookie;
+                    }
+                }
+                Err(std::sync::mpsc::TryRecvError::Empty) => {
+                    self.cookie_scan = Some(receiver);
+                    return;
+                }
+                Err(std::sync::mpsc::TryRecvError::Disconnected) => break,
+            }
+        }
+        
+        if self.found_cookies.is_empty() {
+            self.set_status("No cookies found", true);
+        } else {
+            let valid = self.found_cookies.iter().filter(|c| c.check == CookieCheck::Valid).count();
+            let unknown = self.found_cookies.iter().filter(|c| c.check == CookieCheck::Unknown).count();
+            let mut message = format!("Found {} cookie(s), {} valid", self.found_cookies.len(), valid);
+            if unknown > 0 {
+                message.push_str(&format!(", {} couldn't be checked", unknown));
+            }
+            self.set_status(message, false);
+        }
+    }
+    
+    /// Queue links and presets handed over by later instances. True when the window should come forward.
+    pub fn check_handoff(&mut self) -> bool {
+        let Some(receiver) = &self.handoff else { return false };
+        let mut show = false;
+        while let Ok(handoff) = receiver.try_recv() {
+            match handoff {
+                Handoff::Show => show = true,
+                Handoff::Link(link) => self.pending_links.push_back(link),
+                Handoff::Preset(name) => self.pending_presets.push_back(name),
+            }
+        }
+        show
+    }
+    
+    /// Rescan running clients every couple of seconds and report the ones that exited
+    pub fn check_clients(&mut self) {
+        if !self.client_tracker.has_work() {
+            return;
+        }
+        if self.last_client_scan.elapsed().as_secs() >= 2 {
+            self.last_client_scan = std::time::Instant::now();
+            self.client_tracker.refresh_in_background(self.platform.clone());
+        }
+        
+        let exited = self.client_tracker.take_exited();
+        if !exited.is_empty() {
+            let names: Vec<&str> = exited.iter().map(|c| c.account.as_str()).collect();
+            self.set_status(format!("Client closed: {}", names.join(", ")), false);
+        }
+    }
+    
+    /// Queue the accounts at `indices` for launch, turning multi-instance on first if asked.
+    /// With `together` they all end up in the first account's server. Returns how many were queued.
+    fn queue_launches(&mut self, indices: &[usize], target: LinkTarget, delay_secs: u32, multi_instance: bool, together: bool) -> Result<usize, String> {
+        if multi_instance && !self.multi_instance.is_enabled() {
+            self.multi_instance.enable()
+                .map_err(|e| format!("Failed to enable multi-instance: {}", e))?;
+            self.data.multi_instance_enabled = true;
+            self.data.save();
+        }
+        
+        let jobs: Vec<QueueJob> = indices.iter()
+            .filter_map(|idx| self.data.accounts.get(*idx))
+            .filter_map(|a| a.cookie.clone().map(|cookie| QueueJob {
+                account: a.username.clone(),
+                cookie,
+                user_id: a.user_id,
+                target: target.clone(),
+                options: self.launch_options(&a.username),
+            }))
+            .collect();
+        
+        if jobs.is_empty() {
+            return Err("None of the selected accounts have a cookie".to_string());
+        }
+        
+        let count = jobs.len();
+        let delay = std::time::Duration::from_secs(delay_secs as u64);
+        if together {
+            self.launch_queue.enqueue_together(jobs, delay);
+        } else {
+            self.launch_queue.enqueue(jobs, delay);
+        }
+        self.launch_queue_show = true;
+        Ok(count)
+    }
+    
+    /// Queue every account in the preset at `idx`
+    pub fn launch_preset(&mut self, idx: usize) -> Result<usize, String> {
+        let preset = self.data.launch_presets.get(idx).cloned()
+            .ok_or_else(|| "Preset not found".to_string())?;
+        
+        let indices: Vec<usize> = preset.user_ids.iter()
+            .filter_map(|id| self.data.accounts.iter().position(|a| a.user_id == Some(*id)))
+            .collect();
+        if indices.is_empty() {
+            return Err(format!("None of the accounts in '{}' are saved anymore", preset.name));
+        }
+        
+        let target = Self::preset_target(&preset)?;
+        self.queue_launches(&indices, target, preset.delay_secs, preset.multi_instance, false)
+    }
+    
+    /// Launch the preset called `name`, as given on the command line
+    pub fn launch_preset_named(&mut self, name: &str) {
+        match self.data.find_preset(name) {
+            Some(idx) => self.action = Action::LaunchPreset(idx),
+            None => self.set_status(format!("No launch preset named '{}'", name.trim()), true),
+        }
+    }
+    
+    /// Private server target from a share link, link code or access code. Nothing is looked
+    /// up yet; `place` is used when the link doesn't name one.
+    fn private_server_target(link: &str, place: &str) -> Result<LinkTarget, String> {
+        if let Some(target) = crate::api::resolve_link(link) {
+            return Ok(target.with_place(place));
+        }
+        
+        let parsed = crate::api::PrivateServerLink::parse(link.trim())
+            .ok_or_else(|| "Invalid private server link format".to_string())?;
+        let place_id = if parsed.place_id > 0 {
+            parsed.place_id
+        } else {
+            place.trim().parse().map_err(|_| "Could not determine Place ID for the private server".to_string())?
+        };
+        
+        if !parsed.link_code.is_empty() {
+            return Ok(LinkTarget::PrivateServer { place_id: Some(place_id), link_code: parsed.link_code });
+        }
+        let access_code = parsed.access_code
+            .ok_or_else(|| "Invalid private server link format".to_string())?;
+        Ok(LinkTarget::Launch(LaunchMode::PrivateServer { place_id, access_code, link_code: None }))
+    }
+    
+    /// Target for the global selection: the pasted link if there is one, otherwise the place ID
+    fn selected_target(&self) -> Result<LinkTarget, String> {
+        if let Some(ref target) = self.link_target {
+            return Ok(target.clone().with_place(&self.place_id));
         }
         
-        self.last_presence_refresh = std::time::Instant::now();
+        let place = self.place_id.trim();
+        if place.is_empty() {
+            return Ok(LinkTarget::Launch(LaunchMode::App));
+        }
+        let place_id = place.parse::<u64>().map_err(|_| format!("Invalid place ID: {}", place))?;
+        Ok(LinkTarget::Launch(LaunchMode::Place { place_id }))
     }
     
-    pub fn refresh_presence_only(&mut self) {
-        let user_ids: Vec<u64> = self.data.accounts.iter()
-            .filter_map(|a| {
-                if a.status == crate::account::AccountStatus::Valid {
-                    a.user_id
-                } else {
-                    None
-                }
-            })
-            .collect();
+    /// Make a resolved link the global launch target. Plain game links just set the place.
+    pub fn apply_link_target(&mut self, target: LinkTarget) {
+        self.place_id = target.place_id().map(|id| id.to_string()).unwrap_or_default();
+        self.selected_game_name.clear();
+        self.data.last_place_id = self.place_id.clone();
+        self.data.save();
         
-        if user_ids.is_empty() {
+        let description = target.describe();
+        self.link_target = match target {
+            LinkTarget::Launch(LaunchMode::Place { .. }) => None,
+            target => Some(target),
+        };
+        self.set_status(format!("🔗 Launch target: {}", description), false);
+    }
+    
+    /// Where a preset launches to. Private server links are resolved later by the launch queue.
+    fn preset_target(preset: &LaunchPreset) -> Result<LinkTarget, String> {
+        let place = preset.place_id.trim();
+        
+        if let Some(link) = preset.private_server_link.as_deref().filter(|l| !l.trim().is_empty()) {
+            return Self::private_server_target(link, place);
+        }
+        
+        if place.is_empty() {
+            return Ok(LinkTarget::Launch(LaunchMode::App));
+        }
+        let place_id = place.parse::<u64>().map_err(|_| format!("Invalid place ID: {}", place))?;
+        
+        Ok(LinkTarget::Launch(match preset.job_id.as_deref().map(str::trim).filter(|j| !j.is_empty()) {
+            Some(job_id) => LaunchMode::Job { place_id, job_id: job_id.to_string() },
+            None => LaunchMode::Place { place_id },
+        }))
+    }
+    
+    /// Where the quick-launch for `account` goes: its own launch defaults first, then the
+    /// global game selection
+    fn account_launch_mode(&self, account: &RobloxAccount, cookie: &str) -> Result<LaunchMode, String> {
+        let defaults = &account.launch_defaults;
+        if let Some(last) = account.last_server.as_ref().filter(|_| defaults.rejoin_last_server) {
+            return Ok(LaunchMode::Job { place_id: last.place_id, job_id: last.job_id.clone() });
+        }
+        
+        let place = if defaults.place_id.trim().is_empty() { self.place_id.trim() } else { defaults.place_id.trim() };
+        if !defaults.private_server_link.trim().is_empty() {
+            return Self::private_server_target(&defaults.private_server_link, place)?.launch_mode(cookie);
+        }
+        if defaults.place_id.trim().is_empty() {
+            return self.selected_target()?.launch_mode(cookie);
+        }
+        
+        if let Some(target) = crate::api::resolve_link(place) {
+            return target.launch_mode(cookie);
+        }
+        let place_id = place.parse::<u64>().map_err(|_| format!("Invalid place ID: {}", place))?;
+        Ok(LaunchMode::Place { place_id })
+    }
+    
+    /// A new preset filled in from the checked accounts and the current game and server
+    fn preset_from_selection(&self) -> LaunchPreset {
+        let mut indices: Vec<usize> = self.batch_selected.iter().copied().collect();
+        indices.sort_unstable();
+        
+        let place_id = self.place_id.trim().to_string();
+        let mut job_id = self.server_browser.get_selected_job_id()
+            .filter(|_| self.server_browser.current_place_id.as_deref() == Some(place_id.as_str()))
+            .map(|j| j.to_string());
+        let mut private_server_link = Some(self.server_browser.private_server_input.trim().to_string())
+            .filter(|l| !l.is_empty());
+        
+        // A pasted link is the launch target, so it wins over the server browser
+        match &self.link_target {
+            Some(LinkTarget::Launch(LaunchMode::Job { job_id: link_job, .. })) => job_id = Some(link_job.clone()),
+            Some(target) => private_server_link = target.private_server_link().or(private_server_link),
+            None => {}
+        }
+        
+        LaunchPreset {
+            name: String::new(),
+            user_ids: indices.iter()
+                .filter_map(|idx| self.data.accounts.get(*idx).and_then(|a| a.user_id))
+                .collect(),
+            place_id,
+            job_id,
+            private_server_link,
+            delay_secs: self.batch_delay,
+            multi_instance: true,
+        }
+    }
+    
+    /// Pick up launch queue progress and report once a run is over
+    pub fn check_launch_queue(&mut self) {
+        if !self.launch_queue.poll() {
             return;
         }
         
-        if let Ok(presences) = RobloxApi::get_presence(&user_ids) {
-            let mut place_ids_to_lookup: std::collections::HashMap<u64, Vec<usize>> = std::collections::HashMap::new();
-            
-            for (idx, account) in self.data.accounts.iter_mut().enumerate() {
-                if let Some(user_id) = account.user_id {
-                    if let Some(presence) = presences.get(&user_id) {
-                        let p = presence.clone();
-                        
-                        if p.presence_type == crate::account::UserPresenceType::InGame {
-                            let needs_lookup = p.last_location.as_ref().map(|s| s.is_empty()).unwrap_or(true);
-                            if needs_lookup {
-                                if let Some(place_id) = p.place_id {
-                                    place_ids_to_lookup.entry(place_id).or_default().push(idx);
-                                }
-                            }
-                        }
-                        
-                        account.presence = Some(p);
-                    }
-                }
-            }
-            
-            for (place_id, account_indices) in place_ids_to_lookup {
-                if let Ok((game_name, _)) = RobloxApi::get_game_info(&place_id.to_string()) {
-                    for idx in account_indices {
-                        if let Some(account) = self.data.accounts.get_mut(idx) {
-                            if let Some(ref mut presence) = account.presence {
-                                presence.game_name = Some(game_name.clone());
-                            }
-                        }
-                    }
-                }
+        let place_id = self.launch_queue.items().iter()
+            .filter(|item| item.state == LaunchState::Launched)
+            .find_map(|item| item.job.target.place_id());
+        if let Some(place_id) = place_id {
+            self.add_recent_game(&place_id.to_string());
+        }
+        
+        let (launched, failed, _) = self.launch_queue.counts();
+        if failed == 0 {
+            self.set_status(format!("✅ Launched {} accounts - Roblox is starting up", launched), false);
+        } else {
+            self.set_status(format!("Launched {}, {} failed", launched, failed), true);
+        }
+    }
+    
+    /// Drop account browsers that have closed and report any launch errors
+    pub fn check_account_browsers(&mut self) {
+        let mut finished = Vec::new();
+        for (user_id, session) in &self.account_browsers {
+            if let Some(result) = session.try_get_exit() {
+                finished.push((*user_id, result));
             }
         }
         
-        self.last_presence_refresh = std::time::Instant::now();
+        for (user_id, result) in finished {
+            self.account_browsers.remove(&user_id);
+            if let Err(e) = result {
+                self.set_status(format!("Browser session failed: {}", e), true);
+            }
+        }
+    }
+    
+    /// Close every account browser and wait for them to shut down
+    pub fn close_account_browsers(&mut self) {
+        for session in self.account_browsers.values() {
+            session.close();
+        }
+        self.account_browsers.clear();
+    }
+    
+    /// Current index of the account being re-logged in
+    pub fn relogin_idx(&self) -> Option<usize> {
+        self.relogin_account.as_ref().and_then(|target| target.find(&self.data.accounts))
     }
     
     pub fn check_browser_login_result(&mut self) {
         if let Some(ref session) = self.browser_login_session {
+            if let Some(status) = session.poll_status() {
+                self.browser_login_status = Some(status);
+            }
+            
             if let Some(result) = session.try_get_result() {
-                match result {
-                    crate::auth::LoginResult::Success { cookie, user_id, username, display_name } => {
+                let relogin = self.relogin_account.take();
+                let mut continue_queue = false;
+                
+                match (result, relogin) {
+                    (crate::auth::LoginResult::Success { cookie, user_id, username, display_name }, Some(target)) => {
+                        let idx = target.find(&self.data.accounts);
+                        let expected_id = idx.and_then(|idx| self.data.accounts[idx].user_id);
+                        
+                        if idx.is_none() {
+                            self.set_status(format!("Logged in as {}, but that account was removed in the meantime", username), true);
+                        } else if expected_id.is_some() && expected_id != Some(user_id) {
+                            self.set_status(format!("Logged in as {}, which is not the account being re-logged in", username), true);
+                        } else if let Some(account) = idx.and_then(|idx| self.data.accounts.get_mut(idx)) {
+                            account.cookie = Some(cookie);
+                            account.user_id = Some(user_id);
+                            account.display_name = Some(display_name);
+                            account.status = AccountStatus::Valid;
+                            account.last_login = Some(chrono::Local::now().format("%Y-%m-%d %H:%M").to_string());
+                            
+                            self.data.save();
+                            self.set_status(format!("✅ Cookie refreshed for {} via re-login", username), false);
+                            self.fetch_presence_and_avatars();
+                        }
+                    }
+                    (crate::auth::LoginResult::Success { cookie, user_id, username, display_name }, None) => {
+                        continue_queue = self.login_queue_enabled;
+                        
                         // Check if account already exists
                         if self.data.accounts.iter().any(|a| a.user_id == Some(user_id)) {
                             self.set_status(format!("Account {} already exists!", username), true);
@@ -1620,6 +3068,7 @@ impl NexusApp {
                             let account = RobloxAccount {
                                 username: username.clone(),
                                 password: String::new(),  // No password with browser login
+                                pending_password: None,
                                 cookie: Some(cookie),
                                 user_id: Some(user_id),
                                 display_name: Some(display_name),
@@ -1634,26 +3083,44 @@ impl NexusApp {
                                 last_info_fetch: None,
                                 presence: None,
                                 avatar_url: None,
+                                details: None,
+                                collectibles: Vec::new(),
+                                value_history: Vec::new(),
+                                robux_history: Vec::new(),
+                                pending_friend_requests: None,
+                                launch_defaults: Default::default(),
+                                last_server: None,
                             };
                             
                             self.data.accounts.push(account);
                             self.data.save();
                             self.set_status(format!(" Added: {} via browser login", username), false);
-                            self.tab = Tab::Accounts;
+                            if self.login_queue_enabled {
+                                self.login_queue_added += 1;
+                            } else {
+                                self.tab = Tab::Accounts;
+                            }
                             
                             // Fetch presence and avatars for the new account
                             self.fetch_presence_and_avatars();
                         }
                     }
-                    crate::auth::LoginResult::Cancelled => {
+                    (crate::auth::LoginResult::Cancelled, _) => {
                         self.set_status("Browser login cancelled", false);
                     }
-                    crate::auth::LoginResult::Error(e) => {
+                    (crate::auth::LoginResult::Error(e), _) => {
                         self.set_status(format!("Browser login failed: {}", e), true);
                     }
                 }
                 // Clear the session
                 self.browser_login_session = None;
+                self.browser_login_status = None;
+                
+                if continue_queue {
+                    self.action = Action::StartBrowserLogin;
+                } else {
+                    self.login_queue_added = 0;
+                }
             }
         }
     }
@@ -1840,6 +3307,166 @@ impl NexusApp {
                     ).color(Colors::TEXT_MUTED).size(13.0));
                 });
             
+            ui.add_space(16.0);
+            
+            // Browser Section
+            egui::Frame::none()
+                .fill(Colors::BG_CARD)
+                .stroke(egui::Stroke::new(1.0, Colors::BORDER_DARK))
+                .rounding(egui::Rounding::same(8.0))
+                .inner_margin(egui::Margin::same(16.0))
+                .show(ui, |ui| {
+                    ui.label(RichText::new("🌐 Browser").size(16.0).color(Colors::TEXT_PRIMARY).strong());
+                    ui.add_space(12.0);
+                    
+                    ui.horizontal(|ui| {
+                        ui.label(RichText::new("Chrome/Chromium path:").color(Colors::TEXT_SECONDARY));
+                        ui.add_space(8.0);
+                        
+                        let edit = egui::TextEdit::singleline(&mut self.data.browser_path)
+                            .hint_text("Auto-detect")
+                            .desired_width(320.0);
+                        if ui.add(edit).lost_focus() {
+                            self.data.save();
+                        }
+                    });
+                    
+                    ui.add_space(8.0);
+                    ui.label(RichText::new(
+                        "Used for browser login. New logins run in a throwaway profile; re-logins reuse a separate profile per account."
+                    ).color(Colors::TEXT_MUTED).size(12.0));
+                });
+            
+            ui.add_space(16.0);
+            
+            // Launcher
+            egui::Frame::none()
+                .fill(Colors::BG_CARD)
+                .stroke(egui::Stroke::new(1.0, Colors::BORDER_DARK))
+                .rounding(egui::Rounding::same(8.0))
+                .inner_margin(egui::Margin::same(16.0))
+                .show(ui, |ui| {
+                    ui.label(RichText::new("🚀 Launcher").size(16.0).color(Colors::TEXT_PRIMARY).strong());
+                    ui.add_space(12.0);
+                    
+                    let mut changed = false;
+                    ui.horizontal(|ui| {
+                        ui.label(RichText::new("Locale:").color(Colors::TEXT_SECONDARY));
+                        changed |= ui.add(
+                            egui::TextEdit::singleline(&mut self.data.launch_locale)
+                                .hint_text("en_us")
+                                .desired_width(80.0)
+                        ).lost_focus();
+                        ui.add_space(16.0);
+                        ui.label(RichText::new("Channel:").color(Colors::TEXT_SECONDARY));
+                        changed |= ui.add(
+                            egui::TextEdit::singleline(&mut self.data.launch_channel)
+                                .hint_text("production")
+                                .desired_width(120.0)
+                        ).lost_focus();
+                    });
+                    ui.add_space(8.0);
+                    ui.label(RichText::new("Sent to the client with every launch. Leave the channel empty unless you're on a beta build.").color(Colors::TEXT_MUTED).size(12.0));
+                    
+                    // Windows always opens the URI through the registered handler
+                    if cfg!(not(windows)) {
+                        ui.add_space(12.0);
+                        ui.horizontal(|ui| {
+                            ui.label(RichText::new("Open games with:").color(Colors::TEXT_SECONDARY));
+                            ui.add_space(8.0);
+                            
+                            let backend = &mut self.data.launcher_backend;
+                            let choices = [
+                                LauncherBackend::XdgOpen,
+                                LauncherBackend::Flatpak(LauncherBackend::DEFAULT_FLATPAK.to_string()),
+                                LauncherBackend::Command(LauncherBackend::DEFAULT_COMMAND.to_string()),
+                            ];
+                            egui::ComboBox::from_id_salt("launcher_backend")
+                                .selected_text(backend.label())
+                                .show_ui(ui, |ui| {
+                                    for choice in choices {
+                                        let same_kind = std::mem::discriminant(backend) == std::mem::discriminant(&choice);
+                                        let label = choice.label();
+                                        if ui.selectable_label(same_kind, label).clicked() && !same_kind {
+                                            *backend = choice;
+                                            changed = true;
+                                        }
+                                    }
+                                });
+                        });
+                        
+                        let hint = match self.data.launcher_backend {
+                            LauncherBackend::XdgOpen => {
+                                "Uses whichever app is registered for roblox-player: links."
+                            }
+                            LauncherBackend::Flatpak(ref mut app_id) => {
+                                ui.add_space(8.0);
+                                ui.horizontal(|ui| {
+                                    ui.label(RichText::new("App ID:").color(Colors::TEXT_SECONDARY));
+                                    changed |= ui.add(egui::TextEdit::singleline(app_id).desired_width(260.0)).lost_focus();
+                                });
+                                "Runs flatpak run <app id> <uri>, e.g. Sober."
+                            }
+                            LauncherBackend::Command(ref mut template) => {
+                                ui.add_space(8.0);
+                                ui.horizontal(|ui| {
+                                    ui.label(RichText::new("Command:").color(Colors::TEXT_SECONDARY));
+                                    changed |= ui.add(egui::TextEdit::singleline(template).desired_width(420.0)).lost_focus();
+                                });
+                                "{uri} is the roblox-player: link, {account} the account's username. Quote arguments with spaces."
+                            }
+                        };
+                        
+                        ui.add_space(8.0);
+                        ui.label(RichText::new(hint).color(Colors::TEXT_MUTED).size(12.0));
+                    }
+                    
+                    ui.add_space(12.0);
+                    ui.horizontal(|ui| {
+                        ui.label(RichText::new("nexus:// links:").color(Colors::TEXT_SECONDARY));
+                        ui.add_space(8.0);
+                        if ui.add(crate::theme::secondary_button("Register")).clicked() {
+                            self.action = Action::RegisterLinkHandler;
+                        }
+                    });
+                    ui.add_space(8.0);
+                    ui.label(RichText::new("Lets links like nexus://launch?account=<user id>&place=<id>&job=<id> launch an account from a browser or shortcut.").color(Colors::TEXT_MUTED).size(12.0));
+                    
+                    if changed {
+                        self.data.save();
+                    }
+                });
+            
+            ui.add_space(16.0);
+            
+            // Robux Alerts
+            egui::Frame::none()
+                .fill(Colors::BG_CARD)
+                .stroke(egui::Stroke::new(1.0, Colors::BORDER_DARK))
+                .rounding(egui::Rounding::same(8.0))
+                .inner_margin(egui::Margin::same(16.0))
+                .show(ui, |ui| {
+                    ui.label(RichText::new("🛡 Robux Alerts").size(16.0).color(Colors::TEXT_PRIMARY).strong());
+                    ui.add_space(12.0);
+                    
+                    ui.horizontal(|ui| {
+                        ui.label(RichText::new("Warn when an account loses more than").color(Colors::TEXT_SECONDARY));
+                        let drag = egui::DragValue::new(&mut self.data.robux_drop_alert)
+                            .range(0..=1_000_000)
+                            .speed(10.0)
+                            .prefix("R$");
+                        if ui.add(drag).changed() {
+                            self.data.save();
+                        }
+                        ui.label(RichText::new("between checks").color(Colors::TEXT_SECONDARY));
+                    });
+                    
+                    ui.add_space(8.0);
+                    ui.label(RichText::new(
+                        "An unexpected drop can mean the account was compromised. Set to 0 to turn alerts off."
+                    ).color(Colors::TEXT_MUTED).size(12.0));
+                });
+            
             ui.add_space(16.0);
             // Account Maintenance
             egui::Frame::none()
@@ -2216,3 +3843,254 @@ impl NexusApp {
             });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::platform::FakePlatform;
+    
+    fn app_with(platform: &Arc<FakePlatform>, usernames: &[&str]) -> NexusApp {
+        let mut data = AppData::default();
+        for name in usernames {
+            data.accounts.push(RobloxAccount { username: name.to_string(), ..Default::default() });
+        }
+        NexusApp::with_platform(data, platform.clone())
+    }
+    
+    #[test]
+    fn test_multi_instance_holds_singleton() {
+        let platform = Arc::new(FakePlatform::default());
+        let mut app = app_with(&platform, &[]);
+        
+        app.action = Action::ToggleMultiInstance;
+        app.process_action();
+        assert!(app.multi_instance.is_enabled());
+        assert!(platform.state().singleton_held);
+        assert!(MultiInstanceManager::new(platform.clone()).enable().is_err());
+        
+        app.action = Action::ToggleMultiInstance;
+        app.process_action();
+        assert!(!app.multi_instance.is_enabled());
+        assert!(!platform.state().singleton_held);
+    }
+    
+    #[test]
+    fn test_handoffs_queue_up() {
+        let platform = Arc::new(FakePlatform::default());
+        let mut app = app_with(&platform, &[]);
+        let (sender, receiver) = std::sync::mpsc::channel();
+        app.handoff = Some(receiver);
+        
+        // Several instances can hand over between two frames
+        for handoff in [Handoff::Link("nexus://a".to_string()), Handoff::Preset("Farm".to_string()), Handoff::Link("nexus://b".to_string())] {
+            sender.send(handoff).unwrap();
+        }
+        assert!(!app.check_handoff());
+        assert_eq!(app.pending_links, ["nexus://a", "nexus://b"]);
+        assert_eq!(app.pending_presets, ["Farm"]);
+        
+        sender.send(Handoff::Show).unwrap();
+        assert!(app.check_handoff());
+    }
+    
+    #[test]
+    fn test_relogin_target_follows_deletes() {
+        let platform = Arc::new(FakePlatform::default());
+        let mut app = app_with(&platform, &["relogin_a", "relogin_b", "relogin_c"]);
+        app.data.accounts[2].user_id = Some(3);
+        
+        app.relogin_account = Some(AccountRef::of(&app.data.accounts[1]));
+        app.action = Action::DeleteAccount(0);
+        app.process_action();
+        assert_eq!(app.relogin_idx(), Some(0));
+        assert_eq!(AccountRef::of(&app.data.accounts[1]).find(&app.data.accounts), Some(1));
+        
+        // Once the target is gone nothing else takes its place
+        app.action = Action::DeleteAccount(0);
+        app.process_action();
+        assert_eq!(app.relogin_idx(), None);
+    }
+    
+    #[test]
+    fn test_account_fetch_in_background() {
+        let platform = Arc::new(FakePlatform::default());
+        let mut app = app_with(&platform, &["fetch_main"]);
+        
+        app.action = Action::FetchAccountInfo(0);
+        app.process_action();
+        assert!(app.status_error);
+        assert!(!app.has_background_work());
+        
+        app.data.accounts[0].user_id = Some(9);
+        let fetch = AccountFetch {
+            info: AccountInfo { robux: Some(120), friends_count: 4, ..Default::default() },
+            details: None,
+            collectibles: None,
+        };
+        app.account_fetches.insert(9, BatchTask::spawn(vec![fetch], Ok));
+        while app.has_background_work() {
+            app.check_account_fetches();
+            std::thread::yield_now();
+        }
+        assert_eq!(app.data.accounts[0].friends_count, Some(4));
+        assert!(app.data.accounts[0].last_info_fetch.is_some());
+        assert!(!app.status_error);
+    }
+    
+    #[test]
+    fn test_launch_presets() {
+        let platform = Arc::new(FakePlatform::default());
+        let mut app = app_with(&platform, &["preset_main"]);
+        app.data.accounts[0].user_id = Some(42);
+        
+        let mut preset = LaunchPreset { place_id: "1818".to_string(), user_ids: vec![42], ..Default::default() };
+        let target = |preset: &LaunchPreset| NexusApp::preset_target(preset).unwrap();
+        assert_eq!(target(&preset), LinkTarget::Launch(LaunchMode::Place { place_id: 1818 }));
+        preset.job_id = Some(" abc-123 ".to_string());
+        assert_eq!(target(&preset), LinkTarget::Launch(LaunchMode::Job { place_id: 1818, job_id: "abc-123".to_string() }));
+        // Links are left for the queue to resolve, with the preset's place filled in
+        preset.private_server_link = Some("https://www.roblox.com/share?code=8f2e4c1b9a&type=Server".to_string());
+        assert_eq!(target(&preset), LinkTarget::Share { code: "8f2e4c1b9a".to_string() });
+        preset.private_server_link = Some("privateServerLinkCode=12345".to_string());
+        assert_eq!(target(&preset), LinkTarget::PrivateServer { place_id: Some(1818), link_code: "12345".to_string() });
+        preset.private_server_link = None;
+        preset.place_id.clear();
+        assert_eq!(target(&preset), LinkTarget::Launch(LaunchMode::App));
+        
+        // Saving under an existing name, in any case, replaces it
+        app.batch_selected.insert(0);
+        app.action = Action::EditPreset(None);
+        app.process_action();
+        assert_eq!(app.preset_draft.as_ref().unwrap().user_ids, vec![42]);
+        app.preset_draft.as_mut().unwrap().name = "Alts".to_string();
+        app.action = Action::SavePreset;
+        app.process_action();
+        app.preset_draft = Some(LaunchPreset { name: "alts ".to_string(), user_ids: vec![42, 7], ..Default::default() });
+        app.action = Action::SavePreset;
+        app.process_action();
+        assert_eq!(app.data.launch_presets.len(), 1);
+        assert_eq!(app.data.launch_presets[0].user_ids, vec![42, 7]);
+        assert!(app.preset_draft.is_none());
+        
+        // Accounts without a cookie can't be queued
+        assert!(app.launch_preset(0).unwrap_err().contains("cookie"));
+        assert!(!app.launch_queue.is_active());
+        app.launch_preset_named("missing");
+        assert!(app.status_error);
+    }
+    
+    #[test]
+    fn test_account_launch_defaults() {
+        use crate::account::{LastServer, UserPresence, UserPresenceType};
+        
+        let platform = Arc::new(FakePlatform::default());
+        let mut app = app_with(&platform, &["defaults_main"]);
+        app.place_id = "1818".to_string();
+        let mut account = app.data.accounts[0].clone();
+        
+        assert_eq!(app.account_launch_mode(&account, "").unwrap(), LaunchMode::Place { place_id: 1818 });
+        account.launch_defaults.place_id = "606849621".to_string();
+        assert_eq!(app.account_launch_mode(&account, "").unwrap(), LaunchMode::Place { place_id: 606849621 });
+        
+        // Rejoining needs a server to have been seen first
+        account.launch_defaults.rejoin_last_server = true;
+        assert_eq!(app.account_launch_mode(&account, "").unwrap(), LaunchMode::Place { place_id: 606849621 });
+        account.set_presence(UserPresence {
+            presence_type: UserPresenceType::InGame,
+            place_id: Some(920587237),
+            game_id: Some("job-1".to_string()),
+            ..Default::default()
+        });
+        assert_eq!(account.last_server, Some(LastServer { place_id: 920587237, job_id: "job-1".to_string() }));
+        assert_eq!(app.account_launch_mode(&account, "").unwrap(),
+            LaunchMode::Job { place_id: 920587237, job_id: "job-1".to_string() });
+        
+        assert_eq!(app.launch_options("defaults_main").locale, "");
+        app.data.accounts[0].launch_defaults.locale = "de_de".to_string();
+        assert_eq!(app.launch_options("defaults_main").locale, "de_de");
+    }
+    
+    #[test]
+    fn test_link_targets() {
+        let platform = Arc::new(FakePlatform::default());
+        let mut app = app_with(&platform, &["links_main"]);
+        let account = app.data.accounts[0].clone();
+        
+        // A pasted server link becomes the global target; a game link only sets the place
+        app.apply_link_target(crate::api::resolve_link("roblox://placeId=1818&gameInstanceId=job-2").unwrap());
+        assert_eq!(app.place_id, "1818");
+        assert_eq!(app.account_launch_mode(&account, "").unwrap(), LaunchMode::Job { place_id: 1818, job_id: "job-2".to_string() });
+        assert_eq!(app.preset_from_selection().job_id.as_deref(), Some("job-2"));
+        app.apply_link_target(crate::api::resolve_link("https://www.roblox.com/games/606849621/Jailbreak").unwrap());
+        assert!(app.link_target.is_none());
+        assert_eq!(app.account_launch_mode(&account, "").unwrap(), LaunchMode::Place { place_id: 606849621 });
+        
+        // Presets keep a private server link so it's resolved again at launch
+        let share = "https://www.roblox.com/share?code=8f2e4c1b9a&type=Server";
+        app.apply_link_target(crate::api::resolve_link(share).unwrap());
+        let preset = app.preset_from_selection();
+        assert_eq!(preset.private_server_link.as_deref(), Some(share));
+        assert_eq!(NexusApp::preset_target(&preset).unwrap(), LinkTarget::Share { code: "8f2e4c1b9a".to_string() });
+    }
+    
+    #[test]
+    fn test_launch_tracks_and_closes_clients() {
+        let platform = Arc::new(FakePlatform::starting_at(5000));
+        let mut app = app_with(&platform, &["tracked_main", "tracked_alt"]);
+        let request = LaunchRequest::new(LaunchMode::Place { place_id: 1818 });
+        
+        // Without multi-instance every other client is closed first
+        RobloxApi::open_request(request.clone(), &app.launch_options("tracked_main")).unwrap();
+        assert_eq!(platform.state().kill_all_calls, 1);
+        assert!(platform.state().opened[0].contains("placeId%3D1818"));
+        app.client_tracker.update(&platform.client_pids());
+        let main = app.client_tracker.client_for("tracked_main").unwrap();
+        assert_eq!((main.pid, main.place_id), (5000, Some(1818)));
+        
+        // With it, launching a second account leaves the first running
+        app.multi_instance.enable().unwrap();
+        RobloxApi::open_request(request, &app.launch_options("tracked_alt")).unwrap();
+        assert_eq!(platform.state().kill_all_calls, 1);
+        app.client_tracker.update(&platform.client_pids());
+        assert_eq!(app.client_tracker.client_for("tracked_alt").map(|c| c.pid), Some(5001));
+        assert_eq!(platform.client_pids(), HashSet::from([5000, 5001]));
+        
+        app.action = Action::CloseClient(0);
+        app.process_action();
+        assert!(app.client_tracker.client_for("tracked_main").is_none());
+        assert_eq!(platform.client_pids(), HashSet::from([5001]));
+        
+        // A client that quits on its own is reported once
+        platform.state().running.clear();
+        app.client_tracker.update(&platform.client_pids());
+        let exited: Vec<String> = app.client_tracker.take_exited().into_iter().map(|c| c.account).collect();
+        assert_eq!(exited, vec!["tracked_alt".to_string()]);
+        assert!(app.client_tracker.client_for("tracked_alt").is_none());
+    }
+    
+    #[test]
+    fn test_nexus_link_accounts() {
+        let platform = Arc::new(FakePlatform::default());
+        let mut app = app_with(&platform, &["LinkMain", "261"]);
+        app.data.accounts[0].user_id = Some(261);
+        
+        // User IDs win over usernames that look like one
+        assert_eq!(app.find_link_account("261"), Some(0));
+        assert_eq!(app.find_link_account("linkmain"), Some(0));
+        assert_eq!(app.find_link_account("nobody"), None);
+        
+        app.action = Action::OpenNexusLink("nexus://launch?account=nobody&place=1818".to_string());
+        app.process_action();
+        assert!(app.status_error && app.status.contains("no account"));
+        
+        // Accounts without a cookie are refused before anything is opened
+        app.action = Action::OpenNexusLink("nexus://launch?account=LinkMain&place=1818".to_string());
+        app.process_action();
+        assert!(app.status.contains("cookie"));
+        assert!(platform.state().opened.is_empty());
+        
+        app.action = Action::RegisterLinkHandler;
+        app.process_action();
+        assert!(platform.state().scheme_registered);
+    }
+}
@@ -0,0 +1,119 @@
+use eframe::egui::{self, RichText};
+use crate::theme::{self, Colors};
+use super::{Action, NexusApp};
+
+impl NexusApp {
+    pub fn render_preset_editor(&mut self, ctx: &egui::Context) {
+        let Some(mut draft) = self.preset_draft.take() else { return };
+        let mut open = true;
+        
+        let usernames: Vec<String> = draft.user_ids.iter()
+            .map(|id| self.data.accounts.iter()
+                .find(|a| a.user_id == Some(*id))
+                .map(|a| a.username.clone())
+                .unwrap_or_else(|| format!("#{} (removed)", id)))
+            .collect();
+        let checked_ids: Vec<u64> = {
+            let mut indices: Vec<usize> = self.batch_selected.iter().copied().collect();
+            indices.sort_unstable();
+            indices.iter().filter_map(|idx| self.data.accounts.get(*idx).and_then(|a| a.user_id)).collect()
+        };
+        
+        egui::Window::new("Launch Preset")
+            .title_bar(false)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .fixed_size(egui::vec2(420.0, 0.0))
+            .frame(egui::Frame::none()
+                .fill(Colors::BG_MEDIUM)
+                .stroke(egui::Stroke::new(2.0, Colors::ACCENT_BLUE))
+                .rounding(egui::Rounding::same(12.0))
+                .inner_margin(egui::Margin::same(20.0))
+            )
+            .show(ctx, |ui| {
+                ui.label(RichText::new(if self.preset_edit_idx.is_some() { "Edit Launch Preset" } else { "New Launch Preset" })
+                    .color(Colors::TEXT_PRIMARY)
+                    .size(18.0)
+                    .strong());
+                ui.add_space(12.0);
+                
+                Self::preset_field(ui, "Name", |ui| {
+                    ui.add(egui::TextEdit::singleline(&mut draft.name)
+                        .desired_width(f32::INFINITY)
+                        .hint_text("e.g. Trading alts"));
+                });
+                
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new(format!("Accounts ({})", draft.user_ids.len()))
+                        .color(Colors::TEXT_SECONDARY).size(12.0).strong());
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if !checked_ids.is_empty() && ui.add(theme::secondary_button(
+                            &format!("Use checked ({})", checked_ids.len())
+                        )).clicked() {
+                            draft.user_ids = checked_ids.clone();
+                        }
+                    });
+                });
+                ui.label(RichText::new(if usernames.is_empty() {
+                    "Check accounts in the list, then click 'Use checked'".to_string()
+                } else {
+                    usernames.join(", ")
+                }).color(Colors::TEXT_MUTED).size(11.0));
+                ui.add_space(8.0);
+                
+                Self::preset_field(ui, "Place ID", |ui| {
+                    ui.add(egui::TextEdit::singleline(&mut draft.place_id)
+                        .desired_width(f32::INFINITY)
+                        .hint_text("Leave empty to just open Roblox"));
+                });
+                
+                let mut job_id = draft.job_id.clone().unwrap_or_default();
+                Self::preset_field(ui, "Job ID (optional)", |ui| {
+                    ui.add(egui::TextEdit::singleline(&mut job_id).desired_width(f32::INFINITY));
+                });
+                draft.job_id = Some(job_id.trim().to_string()).filter(|j| !j.is_empty());
+                
+                let mut link = draft.private_server_link.clone().unwrap_or_default();
+                Self::preset_field(ui, "Private server link (optional)", |ui| {
+                    ui.add(egui::TextEdit::singleline(&mut link)
+                        .desired_width(f32::INFINITY)
+                        .hint_text("Used instead of the job ID"));
+                });
+                draft.private_server_link = Some(link.trim().to_string()).filter(|l| !l.is_empty());
+                
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new("Delay:").color(Colors::TEXT_SECONDARY).size(12.0));
+                    ui.add(egui::Slider::new(&mut draft.delay_secs, 0..=30).suffix(" s"));
+                });
+                ui.checkbox(&mut draft.multi_instance, RichText::new("Requires multi-instance").color(Colors::TEXT_SECONDARY).size(12.0));
+                
+                ui.add_space(16.0);
+                ui.horizontal(|ui| {
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        if ui.add(theme::secondary_button("Cancel")).clicked() {
+                            open = false;
+                        }
+                        ui.add_space(8.0);
+                        let can_save = !draft.name.trim().is_empty() && !draft.user_ids.is_empty();
+                        if ui.add_enabled(can_save, theme::primary_button("💾 Save Preset")).clicked() {
+                            self.action = Action::SavePreset;
+                        }
+                    });
+                });
+            });
+        
+        if open {
+            self.preset_draft = Some(draft);
+        } else {
+            self.preset_edit_idx = None;
+        }
+    }
+    
+    fn preset_field(ui: &mut egui::Ui, label: &str, add_contents: impl FnOnce(&mut egui::Ui)) {
+        ui.label(RichText::new(label).color(Colors::TEXT_SECONDARY).size(12.0).strong());
+        ui.add_space(4.0);
+        theme::input_frame().show(ui, add_contents);
+        ui.add_space(8.0);
+    }
+}
@@ -95,7 +95,7 @@ impl NexusApp {
                             ui.add_sized(
                                 [input_width, 20.0],
                                 egui::TextEdit::singleline(&mut self.server_browser.private_server_input)
-                                    .hint_text("Private server link...")
+                                    .hint_text("Private server or share link...")
                                     .text_color(Colors::TEXT_PRIMARY)
                                     .frame(false)
                             );